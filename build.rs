@@ -0,0 +1,48 @@
+//! Generates the solution registry that is included by `src/registry.rs`.
+//! Every solution binary named `YYYY-DD.rs` in `src/bin` becomes a module of the registry.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    // solutions set up their own global allocator for dhat and cannot share a binary.
+    let mut bins: Vec<(String, String)> = if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        vec![]
+    } else {
+        fs::read_dir(&bin_dir)
+            .unwrap()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let stem = path.file_stem()?.to_str()?;
+                let (year, day) = stem.split_once('-')?;
+
+                let is_solution = path.extension()? == "rs"
+                    && year.len() == 4
+                    && day.len() == 2
+                    && (year.to_owned() + day).chars().all(|c| c.is_ascii_digit());
+
+                is_solution.then(|| (format!("y{year}_d{day}"), path.display().to_string()))
+            })
+            .collect()
+    };
+
+    bins.sort();
+
+    let mut registry = String::new();
+
+    // lints are reported when the solution binaries themselves are checked.
+    for (module, path) in &bins {
+        registry += &format!("#[allow(warnings)]\n#[path = {path:?}]\nmod {module};\n\n");
+    }
+
+    registry += "static SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n";
+    for (module, _) in &bins {
+        registry += &format!("    {module}::SOLUTION,\n");
+    }
+    registry += "];\n";
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
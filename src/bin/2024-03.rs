@@ -1,5 +1,5 @@
 use regex::Regex;
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let rex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
@@ -52,13 +52,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(161));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(48));
     }
 }
@@ -0,0 +1,86 @@
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(2024, 4);
+const XMAS: &str = "XMAS";
+
+fn count_xmas(input: &str, substring: &str) -> usize {
+    input
+        .as_bytes()
+        .windows(substring.len())
+        .filter(|&w| w == substring.as_bytes())
+        .count()
+}
+
+fn grid_to_strings(grid: &Grid<char>) -> Vec<String> {
+    let result = grid
+        .iter_rows()
+        .map(|line| line.collect::<String>())
+        .chain(grid.iter_cols().map(|line| line.collect::<String>()))
+        .chain(grid.diagonals().map(|line| line.collect::<String>()))
+        .chain(grid.anti_diagonals().map(|line| line.collect::<String>()))
+        .collect::<Vec<String>>();
+
+    let result_r = result
+        .iter()
+        .map(|s| s.chars().rev().collect::<String>())
+        .collect::<Vec<String>>();
+
+    result.into_iter().chain(result_r).collect()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let strings = grid_to_strings(&grid);
+    let count = strings.iter().map(|s| count_xmas(s, XMAS)).sum::<usize>();
+
+    Some(count as u32)
+}
+
+fn crosses_to_strings(grid: &Grid<char>) -> Vec<String> {
+    let (height, width) = grid.size();
+
+    grid.find_all(|&c| c == 'A')
+        .filter(|&(i, y)| i != 0 && i != height - 1 && y != 0 && y != width - 1)
+        .map(|(i, y)| {
+            [
+                grid[(i - 1, y - 1)],
+                grid[(i - 1, y + 1)],
+                grid[(i, y)],
+                grid[(i + 1, y - 1)],
+                grid[(i + 1, y + 1)],
+            ]
+            .iter()
+            .collect::<String>()
+        })
+        .collect()
+}
+
+fn equal_to_xmas(input: &str) -> bool {
+    let xmas = vec!["MMASS", "MSAMS", "SSAMM", "SMASM"];
+
+    xmas.iter().any(|x| *x == input)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let strings = crosses_to_strings(&grid);
+    let count = strings.iter().filter(|s| equal_to_xmas(s)).count();
+    Some(count as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9));
+    }
+}
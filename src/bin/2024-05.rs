@@ -1,39 +1,39 @@
+use advent_of_code::parse::{integer_array, integers, section_array, ParseError};
 use is_sorted::IsSorted;
 use std::cmp::Ordering::*;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(2024, 5, parse);
+
+/// The order of each pair of pages, followed by the updates.
+type Input = ([[std::cmp::Ordering; 100]; 100], Vec<Vec<usize>>);
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let [rules, pages] = section_array(input)?;
 
-fn read_order_and_pages(input: &str) -> ([[std::cmp::Ordering; 100]; 100], Vec<Vec<usize>>) {
-    let (rules, pages) = input.split_once("\n\n").unwrap();
     let mut order = [[Equal; 100 as usize]; 100 as usize];
 
     // Parse rule "12|45" into format 12 and 45
-    for rule in rules.lines() {
-        let (from, to) = rule.split_once("|").unwrap();
-        let from = from.parse::<usize>().unwrap();
-        let to = to.parse::<usize>().unwrap();
+    let rules = rules.lines(|rule| {
+        let [from, to] = integer_array::<usize, 2>(rule)?;
+        if from.max(to) >= 100 {
+            return Err(ParseError::new(rule, rule, "expected pages below 100"));
+        }
+        Ok((from, to))
+    })?;
 
+    for (from, to) in rules {
         order[from][to] = Less;
         order[to][from] = Greater;
     }
 
     // Parse pages into a vector of usize
     // Example: "75,47,61,53,29" -> [75, 47, 61, 53, 29]
-    let pages = pages
-        .lines()
-        .map(|line| {
-            line.split(",")
-                .map(|page| page.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<Vec<usize>>>();
+    let pages = pages.lines(integers::<usize>)?;
 
-    (order, pages)
+    Ok((order, pages))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (order, pages) = read_order_and_pages(input);
-
+pub fn part_one((order, pages): &Input) -> Option<u32> {
     let sum = pages
         .iter()
         .filter(|page| {
@@ -45,9 +45,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sum as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (order, pages) = read_order_and_pages(input);
-
+pub fn part_two((order, pages): &Input) -> Option<u32> {
     let sum = pages
         .iter()
         .filter(|page| {
@@ -70,13 +68,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_one(&input);
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_two(&input);
         assert_eq!(result, Some(123));
     }
 }
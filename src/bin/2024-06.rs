@@ -1,83 +1,15 @@
+use advent_of_code::geometry::Direction;
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2024, 6);
 
 // Define type aliases
-type Grid = grid::Grid<char>;
+type Grid = advent_of_code::grid::Grid<char>;
 type GridPtr = Box<Grid>;
 type History = HashMap<Direction, HashSet<(usize, usize)>>;
 type HistoryPtr = Box<History>;
 
-fn parse_to_grid(input: &str) -> Grid {
-    let width = input.lines().next().unwrap().len();
-    let cells = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .flatten()
-        .collect::<Vec<char>>();
-
-    Grid::from_vec(cells, width)
-}
-
-#[allow(dead_code)]
-fn print_grid(grid: &Grid) {
-    for ((_row, col), i) in grid.indexed_iter() {
-        print!("{i}");
-        if col == grid.size().0 - 1 {
-            println!();
-        }
-    }
-}
-
-fn start_from(grid: &Grid) -> (usize, usize) {
-    for ((row, col), i) in grid.indexed_iter() {
-        if *i == '^' {
-            return (row, col);
-        }
-    }
-
-    panic!("No starting point found");
-}
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn from_char(c: char) -> Option<Direction> {
-        match c {
-            '^' => Some(Direction::Up),
-            '<' => Some(Direction::Left),
-            'v' => Some(Direction::Down),
-            '>' => Some(Direction::Right),
-            _ => None,
-        }
-    }
-
-    fn turn_right(&self) -> Direction {
-        match *self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-
-    fn to_char(&self) -> char {
-        match *self {
-            Direction::Up => '^',
-            Direction::Left => '<',
-            Direction::Down => 'v',
-            Direction::Right => '>',
-        }
-    }
-}
-
 struct InitState {
     now: (usize, usize),
     direction: Direction,
@@ -87,7 +19,7 @@ struct InitState {
 
 impl InitState {
     fn new(grid: GridPtr, history: HistoryPtr) -> InitState {
-        let now = start_from(&grid);
+        let now = grid.find(|&c| c == '^').expect("No starting point found");
         let direction = Direction::from_char(grid[now]).unwrap();
 
         InitState {
@@ -157,7 +89,7 @@ impl ValidStates {
                 print!("Direction {:?}, ", init.direction);
                 print!("Out of maze: {}", false);
                 println!();
-                print_grid(&init.grid);
+                print!("{}", init.grid);
             }
             ValidStates::Runnable(runnable) => {
                 print!("Runnable state: ");
@@ -165,7 +97,7 @@ impl ValidStates {
                 print!("Direction {:?}, ", runnable.direction);
                 print!("Out of maze: {}", false);
                 println!();
-                print_grid(&runnable.grid);
+                print!("{}", runnable.grid);
             }
             ValidStates::Looping(_) => {
                 println!("Looping");
@@ -179,16 +111,7 @@ impl ValidStates {
 
 impl RunnableState {
     fn step(&self) -> Option<(usize, usize)> {
-        let (row, col) = self.now;
-        let (max_row, max_col) = (self.grid.size().0, self.grid.size().1);
-
-        match self.direction {
-            Direction::Up if row > 0 => Some((row - 1, col)),
-            Direction::Down if row < max_row - 1 => Some((row + 1, col)),
-            Direction::Left if col > 0 => Some((row, col - 1)),
-            Direction::Right if col < max_col - 1 => Some((row, col + 1)),
-            _ => None,
-        }
+        self.grid.step(self.now, self.direction.grid_offset())
     }
 
     // Consume the current state and return the next state
@@ -242,7 +165,7 @@ impl RunnableState {
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let grid = parse_to_grid(input);
+    let grid = Grid::parse(input, |c| c);
     let init_grid = Box::new(grid);
     let history = Box::new(HashMap::new());
 
@@ -263,7 +186,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         _ => 0,
     };
 
-    Some(count as u32)
+    Some(count)
 }
 
 fn fill_grid_with_obstacles(grid: &Grid) -> Vec<Grid> {
@@ -283,7 +206,7 @@ fn fill_grid_with_obstacles(grid: &Grid) -> Vec<Grid> {
 
     let trace = match current_state {
         ValidStates::OutOfMaze(out_of_maze) => *out_of_maze.grid,
-        _ => Grid::new(0, 0),
+        _ => Grid::default(),
     };
 
     // try to replace '.' with '#'
@@ -303,7 +226,7 @@ fn fill_grid_with_obstacles(grid: &Grid) -> Vec<Grid> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let init_grid = parse_to_grid(input);
+    let init_grid = Grid::parse(input, |c| c);
     let grid_with_obstacles = fill_grid_with_obstacles(&init_grid);
     println!(
         "Number of possible obstacles: {}",
@@ -339,13 +262,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6));
     }
 }
@@ -1,20 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use grid::*;
+use advent_of_code::grid::Grid;
 use itertools::Itertools; // 0.8.2
 
-advent_of_code::solution!(8);
-
-fn parse_to_grid(input: &str) -> Grid<char> {
-    let width = input.lines().next().unwrap().len();
-    let cells = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .flatten()
-        .collect::<Vec<char>>();
-
-    Grid::from_vec(cells, width)
-}
+advent_of_code::solution!(2024, 8);
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
 struct Antenna {
@@ -24,33 +13,32 @@ struct Antenna {
 
 fn find_antennas(grid: &Grid<char>) -> HashMap<char, HashSet<Antenna>> {
     let mut antennas = HashMap::new();
-    for ((row, col), i) in grid.indexed_iter() {
-        if *i != '.' {
-            let antenna = Antenna {
-                x: col as i64,
-                y: row as i64,
-            };
-
-            antennas
-                .entry(*i)
-                .or_insert_with(HashSet::new)
-                .insert(antenna);
-        }
+    for (row, col) in grid.find_all(|&c| c != '.') {
+        let antenna = Antenna {
+            x: col as i64,
+            y: row as i64,
+        };
+
+        antennas
+            .entry(grid[(row, col)])
+            .or_insert_with(HashSet::new)
+            .insert(antenna);
     }
 
     antennas
 }
 
 fn project(antenna1: &Antenna, antenna2: &Antenna, grid: &Grid<char>) -> Option<Antenna> {
-    let (width, height) = grid.size();
-    let x = antenna2.x + antenna2.x - antenna1.x;
-    let y = antenna2.y + antenna2.y - antenna1.y;
-
-    if x < 0 || x >= width as i64 || y < 0 || y >= height as i64 {
-        return None;
-    }
-
-    Some(Antenna { x, y })
+    let offset = (
+        (antenna2.y - antenna1.y) as isize,
+        (antenna2.x - antenna1.x) as isize,
+    );
+    let (y, x) = grid.step((antenna2.y as usize, antenna2.x as usize), offset)?;
+
+    Some(Antenna {
+        x: x as i64,
+        y: y as i64,
+    })
 }
 
 fn project2(antenna1: &Antenna, antenna2: &Antenna, grid: &Grid<char>) -> HashSet<Antenna> {
@@ -69,7 +57,7 @@ fn project2(antenna1: &Antenna, antenna2: &Antenna, grid: &Grid<char>) -> HashSe
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let grid = parse_to_grid(input);
+    let grid = Grid::parse(input, |c| c);
     let antennas = find_antennas(&grid);
 
     let locations = antennas
@@ -87,7 +75,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let grid = parse_to_grid(input);
+    let grid = Grid::parse(input, |c| c);
     let antennas = find_antennas(&grid);
 
     let locations = antennas
@@ -111,13 +99,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(14));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(34));
     }
 }
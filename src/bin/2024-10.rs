@@ -1,30 +1,13 @@
 use std::collections::HashSet;
 
-use grid::*;
+use advent_of_code::grid::Grid;
 
-advent_of_code::solution!(10);
-
-fn parse_to_grid(input: &str) -> Grid<u32> {
-    let width = input.lines().next().unwrap().len();
-    let cells = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .flatten()
-        .map(|c| c.to_digit(10).unwrap())
-        .collect::<Vec<u32>>();
-
-    Grid::from_vec(cells, width)
-}
+advent_of_code::solution!(2024, 10);
 
 fn find_start_positions(grid: &Grid<u32>) -> Vec<(usize, usize)> {
-    let mut positions = Vec::new();
-    for ((row, col), i) in grid.indexed_iter() {
-        if *i == 0 {
-            positions.push((col, row));
-        }
-    }
-
-    positions
+    grid.find_all(|&height| height == 0)
+        .map(|(row, col)| (col, row))
+        .collect()
 }
 
 fn find_path(
@@ -42,19 +25,10 @@ fn find_path(
             continue;
         }
 
-        let mut next = Vec::new();
-        if col > 0 {
-            next.push((col - 1, row));
-        }
-        if col < grid.size().1 - 1 {
-            next.push((col + 1, row));
-        }
-        if row > 0 {
-            next.push((col, row - 1));
-        }
-        if row < grid.size().0 - 1 {
-            next.push((col, row + 1));
-        }
+        let next = grid
+            .neighbours4((row, col))
+            .map(|(row, col)| (col, row))
+            .collect::<Vec<_>>();
 
         for (col, row) in next {
             let next_height = grid.get(row, col).unwrap();
@@ -69,7 +43,7 @@ fn find_path(
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    let grid = parse_to_grid(input);
+    let grid = Grid::parse(input, |c| c.to_digit(10).unwrap());
     let start_positions = find_start_positions(&grid);
     let score = start_positions
         .iter()
@@ -86,7 +60,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let grid = parse_to_grid(input);
+    let grid = Grid::parse(input, |c| c.to_digit(10).unwrap());
     let start_positions = find_start_positions(&grid);
     let score = start_positions
         .iter()
@@ -108,13 +82,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(36));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(81));
     }
 }
@@ -1,6 +1,6 @@
 use im::HashMap;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, 11);
 
 fn read_stones(input: &str) -> Vec<u64> {
     input
@@ -96,13 +96,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(55312));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(55312));
     }
 }
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use advent_of_code::grid::Grid;
+use im::HashMap;
+
+advent_of_code::solution!(2024, 12);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+
+    let adjacent_to_perimeter: HashMap<usize, usize> = [(0, 4), (1, 3), (2, 2), (3, 1), (4, 0)]
+        .iter()
+        .cloned()
+        .collect();
+
+    let mut unknown = Vec::new();
+    for ((row, col), _) in grid.indexed_iter() {
+        unknown.push((row, col));
+    }
+
+    let mut total_price = 0;
+    while !unknown.is_empty() {
+        let position = unknown.pop().unwrap();
+        let mut queue = vec![position];
+        let mut visited = HashSet::new();
+
+        while !queue.is_empty() {
+            let now = queue.pop().unwrap();
+            let plant = grid[now];
+            visited.insert(now);
+
+            for next in grid.neighbours4(now) {
+                if grid[next] == plant && !visited.contains(&next) {
+                    queue.push(next);
+                }
+            }
+        }
+
+        let area = visited.len();
+        let mut perimeter = 0;
+        for position in &visited {
+            let neighbor = grid
+                .neighbours4(*position)
+                .filter(|next| visited.contains(next))
+                .count();
+
+            let adjacent = adjacent_to_perimeter.get(&neighbor).unwrap();
+            perimeter += *adjacent;
+        }
+
+        total_price += area * perimeter;
+
+        for position in visited {
+            unknown.retain(|&x| x != position);
+        }
+    }
+
+    Some(total_price as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    // skip part two for now
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1930));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1930));
+    }
+}
@@ -1,7 +1,9 @@
-advent_of_code::solution!(13);
+use advent_of_code::parse::{integer_array, sections, ParseError};
+
+advent_of_code::solution!(2024, 13, parse);
 
 #[derive(Debug)]
-struct ClawMachine {
+pub struct ClawMachine {
     a_x: isize,
     a_y: isize,
     b_x: isize,
@@ -10,27 +12,25 @@ struct ClawMachine {
     prize_y: isize,
 }
 
-fn parse_input(s: &str) -> Vec<ClawMachine> {
-    s.split("\n\n")
-        .map(|m| {
-            let mut lines = m.lines().map(|line| line.split_once(", ").unwrap());
-            let (a_x, a_y) = lines.next().unwrap();
-            let a_x = a_x.trim_start_matches("Button A: X+").parse().unwrap();
-            let a_y = a_y.trim_start_matches("Y+").parse().unwrap();
-            let (b_x, b_y) = lines.next().unwrap();
-            let b_x = b_x.trim_start_matches("Button B: X+").parse().unwrap();
-            let b_y = b_y.trim_start_matches("Y+").parse().unwrap();
-            let (prize_x, prize_y) = lines.next().unwrap();
-            let prize_x = prize_x.trim_start_matches("Prize: X=").parse().unwrap();
-            let prize_y = prize_y.trim_start_matches("Y=").parse().unwrap();
-            ClawMachine {
+type Input = Vec<ClawMachine>;
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    sections(input)
+        .iter()
+        .map(|section| {
+            let numbers = section.lines(integer_array::<isize, 2>)?;
+            let [[a_x, a_y], [b_x, b_y], [prize_x, prize_y]] = numbers[..] else {
+                return Err(section.error("expected two buttons and a prize"));
+            };
+
+            Ok(ClawMachine {
                 a_x,
                 a_y,
                 b_x,
                 b_y,
                 prize_x,
                 prize_y,
-            }
+            })
         })
         .collect()
 }
@@ -46,8 +46,7 @@ fn solve(c: &ClawMachine) -> Option<(isize, isize)> {
     Some((n_a, n_b))
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let claw_machines = parse_input(input);
+pub fn part_one(claw_machines: &Input) -> Option<u32> {
     let tokens = claw_machines
         .iter()
         .filter_map(|c| solve(c))
@@ -58,10 +57,9 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(tokens as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let claw_machines = parse_input(input);
+pub fn part_two(claw_machines: &Input) -> Option<u64> {
     let tokens = claw_machines
-        .into_iter()
+        .iter()
         .map(|c| ClawMachine {
             a_x: c.a_x,
             a_y: c.a_y,
@@ -84,13 +82,15 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_one(&input);
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_two(&input);
         assert_eq!(result, Some(875318608908));
     }
 }
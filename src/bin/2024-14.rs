@@ -1,6 +1,14 @@
 use rayon::prelude::*;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2024, 14, params);
+
+advent_of_code::params! {
+    /// The size of the area the robots move in.
+    pub struct Params {
+        width: usize = 101,
+        height: usize = 103,
+    }
+}
 
 #[derive(Debug)]
 struct Robot {
@@ -34,9 +42,8 @@ fn parse(input: &str, width: usize, height: usize) -> Vec<Robot> {
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let width = 11;
-    let height = 7;
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
+    let (width, height) = (params.width, params.height);
     let robots = parse(input, width, height);
     let mut q1 = 0;
     let mut q2 = 0;
@@ -94,9 +101,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some((q1 * q2 * q3 * q4) as u32)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let width = 101;
-    let height = 103;
+pub fn part_two(input: &str, params: &Params) -> Option<usize> {
+    let (width, height) = (params.width, params.height);
     let robots = parse(input, width, height);
     let minimum_time = (0..(width * height * 1000) as usize)
         .into_par_iter()
@@ -134,13 +140,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
         assert_eq!(result, Some(12));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
         assert_eq!(result, None);
     }
 }
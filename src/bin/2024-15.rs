@@ -1,37 +1,16 @@
+use advent_of_code::geometry::Direction;
 use std::collections::HashSet;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
-type Grid = grid::Grid<char>;
+type Grid = advent_of_code::grid::Grid<char>;
 type GridPtr = Box<Grid>;
 type Moves = Vec<Direction>;
 
-#[allow(dead_code)]
-fn print_grid(grid: &Grid) {
-    let (_max_row, max_col) = (grid.size().0, grid.size().1);
-    for ((_row, col), i) in grid.indexed_iter() {
-        print!("{i}");
-        if col == max_col - 1 {
-            println!();
-        }
-    }
-}
-
-fn parse_to_grid(input: &str) -> Grid {
-    let width = input.lines().next().unwrap().len();
-    let cells = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .flatten()
-        .collect::<Vec<char>>();
-
-    Grid::from_vec(cells, width)
-}
-
 fn read_grid_and_moves(input: &str) -> (Grid, Moves) {
     // split by empty line
     let mut parts = input.split("\n\n");
-    let grid = parse_to_grid(parts.next().unwrap());
+    let grid = Grid::parse(parts.next().unwrap(), |c| c);
     let moves = parts
         .next()
         .unwrap()
@@ -42,28 +21,9 @@ fn read_grid_and_moves(input: &str) -> (Grid, Moves) {
     (grid, moves)
 }
 
-fn start_from(grid: &Grid) -> (usize, usize) {
-    for ((row, col), i) in grid.indexed_iter() {
-        if *i == '@' {
-            return (row, col);
-        }
-    }
-
-    panic!("No starting point found");
-}
-
 fn next_cell(grid: &Grid, start: (usize, usize), dir: Direction) -> (usize, usize) {
-    let (max_row, max_col) = (grid.size().0, grid.size().1);
-    let (row, col) = start;
-    let next = match dir {
-        Direction::Up if row > 0 => (row - 1, col),
-        Direction::Down if row < max_row - 1 => (row + 1, col),
-        Direction::Left if col > 0 => (row, col - 1),
-        Direction::Right if col < max_col - 1 => (row, col + 1),
-        _ => unreachable!("Out of bounds"),
-    };
-
-    next
+    grid.step(start, dir.grid_offset())
+        .unwrap_or_else(|| unreachable!("Out of bounds"))
 }
 
 fn can_push_towards(
@@ -140,36 +100,6 @@ fn empty_cell_along_direction(
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn from_char(c: char) -> Option<Direction> {
-        match c {
-            '^' => Some(Direction::Up),
-            '<' => Some(Direction::Left),
-            'v' => Some(Direction::Down),
-            '>' => Some(Direction::Right),
-            _ => None,
-        }
-    }
-
-    #[allow(dead_code)]
-    fn to_char(&self) -> char {
-        match *self {
-            Direction::Up => '^',
-            Direction::Left => '<',
-            Direction::Down => 'v',
-            Direction::Right => '>',
-        }
-    }
-}
-
 struct InitState {
     now: (usize, usize),
     moves: Moves,
@@ -179,7 +109,7 @@ struct InitState {
 
 impl InitState {
     fn new(grid: GridPtr, moves: Moves, push: bool) -> InitState {
-        let now = start_from(&grid);
+        let now = grid.find(|&c| c == '@').expect("No starting point found");
         InitState {
             now,
             moves,
@@ -267,14 +197,14 @@ impl ValidStates {
                 print!("Now at {:?}, ", init.now);
                 print!("Moves: {:?}, ", init.moves);
                 println!();
-                print_grid(&init.grid);
+                print!("{}", init.grid);
             }
             ValidStates::Runnable(runnable) => {
                 print!("Runnable state: ");
                 print!("Now at {:?}, ", runnable.now);
                 print!("Moves {:?}, ", runnable.moves);
                 println!();
-                print_grid(&runnable.grid);
+                print!("{}", runnable.grid);
             }
             ValidStates::Pushing(pushing) => {
                 print!("Pushing state: ");
@@ -283,11 +213,11 @@ impl ValidStates {
                     print!(", facing {:?}", direction);
                 }
                 println!();
-                print_grid(&pushing.grid);
+                print!("{}", pushing.grid);
             }
             ValidStates::OutOfMaze(out_of_maze) => {
                 println!("Out of maze:");
-                print_grid(&out_of_maze.grid);
+                print!("{}", out_of_maze.grid);
                 println!("score: {}", out_of_maze.score);
             }
         }
@@ -544,13 +474,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(10092));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(9021));
     }
 }
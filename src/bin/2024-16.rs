@@ -0,0 +1,89 @@
+use advent_of_code::geometry::{Direction, Point};
+use advent_of_code::grid::Grid;
+use advent_of_code::search::dijkstra;
+use std::collections::HashSet;
+
+advent_of_code::solution!(2024, 16);
+
+type CharGrid = Grid<char>;
+
+fn minimum_cost_from_to(grid: &CharGrid, start: Point, end: Point) -> (usize, usize) {
+    let next = |&(position, direction): &(Point, Direction)| {
+        [
+            // Move forward
+            ((position + direction.offset(), direction), 1),
+            ((position, direction.turn_left()), 1000),
+            ((position, direction.turn_right()), 1000),
+        ]
+        .into_iter()
+        .filter(|((next_position, _), _)| grid[*next_position] != '#')
+    };
+
+    // Lowest paths can arrive at end node in multiple directions.
+    let paths = dijkstra((start, Direction::Right), next, |&(position, _)| {
+        position == end
+    });
+
+    let tiles = paths
+        .nodes_on_paths()
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect::<HashSet<_>>();
+
+    (paths.cost().unwrap(), tiles.len())
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = Grid::parse(input, |c| c);
+    let start = grid
+        .find(|&c| c == 'S')
+        .and_then(Point::from_index)
+        .unwrap();
+    let end = grid
+        .find(|&c| c == 'E')
+        .and_then(Point::from_index)
+        .unwrap();
+    let (minimum_cost, _) = minimum_cost_from_to(&grid, start, end);
+    println!(
+        "Going from {:?} to {:?}, minimum_cost {:?}",
+        start, end, minimum_cost
+    );
+
+    Some(minimum_cost)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let grid = Grid::parse(input, |c| c);
+    let start = grid
+        .find(|&c| c == 'S')
+        .and_then(Point::from_index)
+        .unwrap();
+    let end = grid
+        .find(|&c| c == 'E')
+        .and_then(Point::from_index)
+        .unwrap();
+    let (_, total_length) = minimum_cost_from_to(&grid, start, end);
+    println!(
+        "Going from {:?} to {:?}, total_length {:?}",
+        start, end, total_length
+    );
+
+    Some(total_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(7036));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(45));
+    }
+}
@@ -3,7 +3,7 @@ use std::ops::ControlFlow;
 use itertools::Itertools;
 use rayon::prelude::*;
 
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, 17);
 
 type Integer = i64;
 type Bits = u8;
@@ -282,13 +282,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(117440));
     }
 
@@ -0,0 +1,92 @@
+use advent_of_code::geometry::Point;
+use advent_of_code::grid::Grid;
+use advent_of_code::search::bfs;
+use rayon::prelude::*;
+
+advent_of_code::solution!(2024, 18, params);
+
+advent_of_code::params! {
+    /// The size of the memory space and the number of bytes that fell in part one.
+    pub struct Params {
+        size: usize = 71,
+        bytes: usize = 1024,
+    }
+}
+
+type CharGrid = Grid<char>;
+
+fn parse_to_grid(input: &str, size: usize, num: usize) -> CharGrid {
+    let mut grid = CharGrid::init(size, size, '.');
+    let bytes = input
+        .lines()
+        // Parse line like '5, 4' into a Point.
+        .map(|line| {
+            let mut iter = line.split(",");
+            let x = iter.next().unwrap().parse::<i32>().unwrap();
+            let y = iter.next().unwrap().parse::<i32>().unwrap();
+
+            Point::new(x, y)
+        })
+        .take(num)
+        .collect::<Vec<Point>>();
+
+    for point in bytes {
+        grid[point] = '#';
+    }
+
+    grid
+}
+
+/// The fewest steps to reach the end, if it can be reached at all.
+fn minimum_steps_from_to(grid: &CharGrid, start: Point, end: Point) -> Option<usize> {
+    let next = |position: &Point| {
+        grid.neighbours4(position.to_index().unwrap())
+            .filter(|&next_position| grid[next_position] != '#')
+            .filter_map(Point::from_index)
+    };
+
+    bfs(start, next, |&position| position == end).cost()
+}
+
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
+    let grid = parse_to_grid(input, params.size, params.bytes);
+    let end = Point::new(params.size as i32 - 1, params.size as i32 - 1);
+    let steps = minimum_steps_from_to(&grid, Point::new(0, 0), end);
+
+    steps.map(|steps| steps as u32)
+}
+
+pub fn part_two(input: &str, params: &Params) -> Option<String> {
+    let max_length = input.lines().count();
+    let end = Point::new(params.size as i32 - 1, params.size as i32 - 1);
+    let num = (1..max_length).into_par_iter().find_first(|n| {
+        let grid = parse_to_grid(input, params.size, *n);
+        minimum_steps_from_to(&grid, Point::new(0, 0), end).is_none()
+    });
+
+    let byte = input.lines().nth(num.unwrap() - 1).unwrap().to_string();
+    Some(byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some(22));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some("6,1".to_string()));
+    }
+}
@@ -0,0 +1,173 @@
+use advent_of_code::geometry::Point;
+use advent_of_code::grid::Grid;
+use advent_of_code::search::bfs;
+use std::collections::HashMap;
+
+advent_of_code::solution!(2024, 20, params);
+
+advent_of_code::params! {
+    /// The minimum number of picoseconds a cheat has to save to be counted.
+    pub struct Params {
+        threshold: u32 = 100,
+    }
+}
+
+type CharGrid = Grid<char>;
+type DistanceGrid = Grid<i32>;
+
+fn distance_from_to(grid: &CharGrid, start: Point, end: Point) -> DistanceGrid {
+    let (height, width) = grid.size();
+    let mut distance_grid = Grid::init(height, width, i32::MAX);
+
+    let next = |position: &Point| {
+        grid.neighbours4(position.to_index().unwrap())
+            .filter(|&next_position| grid[next_position] != '#')
+            .filter_map(Point::from_index)
+    };
+
+    for (position, elapsed) in bfs(start, next, |&position| position == end).costs {
+        distance_grid[position] = elapsed as i32;
+    }
+
+    distance_grid
+}
+
+fn cheat_from(distances: &DistanceGrid, first: Point, delta: Point) -> u32 {
+    let second = first + delta;
+    let Some(&second_time) = distances.get_point(second) else {
+        return 0;
+    };
+
+    // Land on track.
+    if second_time != i32::MAX {
+        // We can save time if we can reach the second point in less time.
+        let first_time = distances[first];
+        let cost = first.manhattan(second);
+        let saved = (first_time - second_time).abs();
+
+        return (saved - cost) as u32;
+    }
+
+    0
+}
+
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let (width, height) = grid.size();
+    let start = grid
+        .find(|&c| c == 'S')
+        .and_then(Point::from_index)
+        .unwrap();
+    let end = grid
+        .find(|&c| c == 'E')
+        .and_then(Point::from_index)
+        .unwrap();
+    let distances = distance_from_to(&grid, start, end);
+
+    let path = (1..height - 1)
+        .flat_map(|row| (1..width - 1).map(move |col| (row, col)))
+        .filter_map(|(row, col)| {
+            let point = Point::new(col as i32, row as i32);
+            if grid[point] == '#' {
+                return None;
+            } else {
+                return Some(point);
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut saved_time_map = HashMap::new();
+    for point in path {
+        let jumps = vec![Point::new(2, 0), Point::new(0, 2)];
+        for jump in jumps {
+            let saved_time = cheat_from(&distances, point, jump);
+            *saved_time_map.entry(saved_time).or_insert(0) += 1;
+        }
+    }
+
+    // sum values of keys that are at least the threshold
+    let cheats = saved_time_map
+        .iter()
+        .filter(|(key, _)| **key >= params.threshold)
+        .map(|(_, value)| *value)
+        .sum::<u32>();
+
+    Some(cheats)
+}
+
+pub fn part_two(input: &str, params: &Params) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let (width, height) = grid.size();
+    let start = grid
+        .find(|&c| c == 'S')
+        .and_then(Point::from_index)
+        .unwrap();
+    let end = grid
+        .find(|&c| c == 'E')
+        .and_then(Point::from_index)
+        .unwrap();
+    let distances = distance_from_to(&grid, start, end);
+
+    let path = (1..height - 1)
+        .flat_map(|row| (1..width - 1).map(move |col| (row, col)))
+        .filter_map(|(row, col)| {
+            let point = Point::new(col as i32, row as i32);
+            if grid[point] == '#' {
+                return None;
+            } else {
+                return Some(point);
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut saved_time_map = HashMap::new();
+    for point in path {
+        let mut jumps = Vec::new();
+        for x in 2..21 {
+            jumps.push(Point::new(x, 0));
+        }
+
+        for y in 1..21 {
+            for x in (y - 20)..(21 - y) {
+                jumps.push(Point::new(x, y));
+            }
+        }
+
+        for jump in jumps {
+            let saved_time = cheat_from(&distances, point, jump);
+            *saved_time_map.entry(saved_time).or_insert(0) += 1;
+        }
+    }
+
+    // sum values of keys that are at least the threshold
+    let cheats = saved_time_map
+        .iter()
+        .filter(|(key, _)| **key >= params.threshold)
+        .map(|(_, value)| *value)
+        .sum::<u32>();
+
+    Some(cheats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some(1));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &advent_of_code::template::read_file("examples", YEAR, DAY),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some(86));
+    }
+}
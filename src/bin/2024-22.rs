@@ -2,7 +2,7 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, 22);
 
 fn parse_input(input: &str) -> Vec<i64> {
     input.lines().map(|line| line.parse().unwrap()).collect()
@@ -102,13 +102,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(37327623));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(23));
     }
 }
@@ -0,0 +1,91 @@
+use advent_of_code::parse::{arrow, key_value, section_array, ParseError};
+use std::collections::{HashMap, VecDeque};
+
+advent_of_code::solution!(2024, 24, parse);
+
+/// The initial wire values and the gates as `[left, kind, right, "->", to]`.
+type Input = (HashMap<String, bool>, Vec<[String; 5]>);
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let [prefix, suffix] = section_array(input)?;
+
+    let values = prefix.lines(|line| {
+        let (key, value) = key_value(line, ":")?;
+        match value {
+            "0" | "1" => Ok((key.to_string(), value == "1")),
+            _ => Err(ParseError::new(line, value, "expected `0` or `1`")),
+        }
+    })?;
+
+    let gates = suffix.lines(|line| {
+        let (gate, to) = arrow(line)?;
+        let parts = gate.split_whitespace().collect::<Vec<_>>();
+        let [left, kind @ ("AND" | "OR" | "XOR"), right] = parts[..] else {
+            return Err(ParseError::new(line, gate, "expected `a AND|OR|XOR b`"));
+        };
+
+        Ok([left, kind, right, "->", to].map(str::to_string))
+    })?;
+
+    Ok((values.into_iter().collect(), gates))
+}
+
+pub fn part_one((values, gates): &Input) -> Option<u64> {
+    let mut todo: VecDeque<_> = gates.iter().collect();
+    let mut values = values.clone();
+
+    while let Some(gate @ [left, kind, right, _, to]) = todo.pop_front() {
+        let left = values.get(left);
+        let right = values.get(right);
+
+        if left.is_some() && right.is_some() {
+            let new_value = match kind.as_str() {
+                "AND" => left.unwrap() & right.unwrap(),
+                "OR" => left.unwrap() | right.unwrap(),
+                "XOR" => left.unwrap() ^ right.unwrap(),
+                _ => unreachable!(),
+            };
+            values.insert(to.to_string(), new_value);
+        } else {
+            todo.push_back(gate);
+        }
+    }
+
+    // filter out key-value pairs whose key start with z
+    let mut gates_z = values
+        .iter()
+        .filter(|(key, _)| key.starts_with('z'))
+        .collect::<Vec<_>>();
+    gates_z.sort_by_key(|(key, _)| (*key).clone());
+
+    let mut result = 0;
+    for (key, &value) in gates_z.into_iter().rev() {
+        println!("{}: {}", key, value);
+        result = (result << 1) | (value as u64);
+    }
+
+    Some(result)
+}
+
+pub fn part_two(_input: &Input) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_one(&input);
+        assert_eq!(result, Some(2024));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
+        let result = part_two(&input);
+        assert_eq!(result, None);
+    }
+}
@@ -1,37 +1,20 @@
-advent_of_code::solution!(25);
+advent_of_code::solution!(2024, 25);
 
-type Grid = grid::Grid<char>;
+use advent_of_code::grid::Grid;
 
-fn parse_to_grid(input: &str) -> Grid {
-    let width = input.lines().next().unwrap().len();
-    let cells = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .flatten()
-        .collect::<Vec<char>>();
-
-    Grid::from_vec(cells, width)
-}
 struct Key(Vec<u32>);
 
 impl Key {
     fn try_from(input: &str) -> Option<Self> {
-        let grid = parse_to_grid(input);
-        let (height, width) = grid.size();
+        let grid = Grid::parse(input, |c| c);
         if grid[(0, 0)] != '.' {
             return None;
         }
 
-        let mut key = Vec::new();
-        for x in 0..width {
-            let mut value = 0;
-            for y in 0..height {
-                if grid[(y, x)] == '#' {
-                    value += 1;
-                }
-            }
-            key.push(value);
-        }
+        let key = grid
+            .iter_cols()
+            .map(|column| column.filter(|&&c| c == '#').count() as u32)
+            .collect();
 
         Some(Self(key))
     }
@@ -41,22 +24,15 @@ struct Lock(Vec<u32>);
 
 impl Lock {
     fn try_from(input: &str) -> Option<Self> {
-        let grid = parse_to_grid(input);
-        let (height, width) = grid.size();
+        let grid = Grid::parse(input, |c| c);
         if grid[(0, 0)] != '#' {
             return None;
         }
 
-        let mut key = Vec::new();
-        for x in 0..width {
-            let mut value = 0;
-            for y in 0..height {
-                if grid[(y, x)] == '#' {
-                    value += 1;
-                }
-            }
-            key.push(value);
-        }
+        let key = grid
+            .iter_cols()
+            .map(|column| column.filter(|&&c| c == '#').count() as u32)
+            .collect();
 
         Some(Self(key))
     }
@@ -144,13 +120,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,303 @@
+/// Points and directions on a 2D plane, as used by the puzzles that walk a map.
+/// `x` grows to the right and `y` grows downwards, so `y` is the row and `x` the column of a grid.
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or a vector between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Point<T> {
+    /// The distance when moving in the four orthogonal directions only.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The distance when diagonal moves are allowed as well.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Neg<Output = T>> Point<T> {
+    /// Rotates the vector by 90 degrees clockwise, e.g. from up to right.
+    pub fn clockwise(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates the vector by 90 degrees counter-clockwise, e.g. from up to left.
+    pub fn counter_clockwise(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: TryInto<usize>> Point<T> {
+    /// Converts the point to the `(row, col)` index of a grid, if neither coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+}
+
+impl<T: TryFrom<usize>> Point<T> {
+    /// Converts the `(row, col)` index of a grid to a point, if it fits the coordinate type.
+    pub fn from_index((row, col): (usize, usize)) -> Option<Self> {
+        Some(Self::new(col.try_into().ok()?, row.try_into().ok()?))
+    }
+}
+
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses an arrow like `^`, `>`, `v` or `<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow that [`Direction::from_char`] parses.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point<T> {
+        Direction8::from(self).offset()
+    }
+
+    /// The `(row, col)` offset of a single step, e.g. for [`Grid::step`](crate::grid::Grid::step).
+    pub fn grid_offset(self) -> (isize, isize) {
+        Direction8::from(self).grid_offset()
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn offset<T: From<i8>>(self) -> Point<T> {
+        let (dx, dy) = self.deltas();
+        Point::new(dx.into(), dy.into())
+    }
+
+    /// The `(row, col)` offset of a single step, e.g. for [`Grid::step`](crate::grid::Grid::step).
+    pub fn grid_offset(self) -> (isize, isize) {
+        let (dx, dy) = self.deltas();
+        (dy.into(), dx.into())
+    }
+
+    fn deltas(self) -> (i8, i8) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Direction8, Point};
+
+    #[test]
+    fn computes_distances() {
+        let a = Point::new(1, 5);
+        let b = Point::new(4, 1);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::<u8>::new(1, 5).manhattan(Point::new(4, 1)), 7);
+    }
+
+    #[test]
+    fn rotates_vectors() {
+        let up: Point = Direction::Up.offset();
+        assert_eq!(up.clockwise(), Direction::Right.offset());
+        assert_eq!(up.counter_clockwise(), Direction::Left.offset());
+        assert_eq!(-up, Direction::Down.offset());
+        assert_eq!(Point::new(2, 3) + up * 2, Point::new(2, 1));
+    }
+
+    #[test]
+    fn turns_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(
+                direction.turn_right().offset::<i32>(),
+                direction.offset::<i32>().clockwise()
+            );
+            assert_eq!(Direction::from_char(direction.to_char()), Some(direction));
+        }
+
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpRight.reverse(), Direction8::DownLeft);
+        assert_eq!(Direction::from_char('.'), None);
+    }
+
+    #[test]
+    fn converts_grid_indices() {
+        assert_eq!(Point::new(3, 1).to_index(), Some((1, 3)));
+        assert_eq!(Point::new(-1, 1).to_index(), None);
+        assert_eq!(Point::<i32>::from_index((1, 3)), Some(Point::new(3, 1)));
+        assert_eq!(Point::<u8>::from_index((1, 300)), None);
+        assert_eq!(Direction::Up.grid_offset(), (-1, 0));
+        assert_eq!(Direction8::DownLeft.grid_offset(), (1, -1));
+    }
+}
@@ -0,0 +1,318 @@
+/// A two-dimensional grid for the many puzzles that come as a map of characters.
+/// Wraps [`grid::Grid`], so its methods like `size`, `get`, `iter_rows`, `iter_cols` and indexing by `(row, col)`
+/// are available as well.
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut, Index, IndexMut},
+};
+
+use crate::geometry::Point;
+
+/// The offsets of the orthogonal neighbours, clockwise starting upwards.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// The offsets of the orthogonal and diagonal neighbours, clockwise starting upwards.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A grid of cells addressed by `(row, col)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T>(::grid::Grid<T>);
+
+impl<T> Grid<T> {
+    /// Parses one row per line, mapping every character to a cell. Empty lines are skipped.
+    /// Panics if the lines differ in length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.lines().filter(|line| !line.is_empty()).collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        let cells: Vec<T> = lines
+            .iter()
+            .inspect(|line| assert_eq!(line.chars().count(), width, "uneven grid row: {line}"))
+            .flat_map(|line| line.chars())
+            .map(&mut cell)
+            .collect();
+
+        Self(::grid::Grid::from_vec(cells, width))
+    }
+
+    /// Creates a grid of `rows` by `cols` cells that all hold `value`.
+    pub fn init(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self(::grid::Grid::init(rows, cols, value))
+    }
+
+    /// Creates a grid of the same size with every cell mapped.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid(::grid::Grid::from_vec(
+            self.0.iter().map(cell).collect(),
+            self.0.cols(),
+        ))
+    }
+
+    /// Finds the first cell in reading order that matches a predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.0
+            .indexed_iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Finds all cells that match a predicate, in reading order.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.0
+            .indexed_iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The orthogonal neighbours of a position that lie within the grid.
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that lie within the grid.
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    /// The cell at a point, if the point lies within the grid.
+    pub fn get_point<C: TryInto<usize>>(&self, point: Point<C>) -> Option<&T> {
+        let (row, col) = point.to_index()?;
+        self.0.get(row, col)
+    }
+
+    /// Steps from a position by an offset, if the result lies within the grid.
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row
+            .checked_add_signed(dr)
+            .filter(|&row| row < self.0.rows())?;
+        let col = col
+            .checked_add_signed(dc)
+            .filter(|&col| col < self.0.cols())?;
+        Some((row, col))
+    }
+
+    fn neighbours(
+        &self,
+        position: (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.step(position, offset))
+    }
+
+    /// The cells of each diagonal that runs down and to the right, starting in the top-right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (rows, cols) = self.cell_size();
+        let starts = (0..cols).rev().map(|col| (0, col));
+        let starts = starts.chain((1..rows).map(|row| (row, 0)));
+
+        starts.map(move |start| self.ray(start, (1, 1)))
+    }
+
+    /// The cells of each diagonal that runs down and to the left, starting in the top-left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (rows, cols) = self.cell_size();
+        let starts = (0..cols).map(|col| (0, col));
+        let starts = starts.chain((1..rows).map(move |row| (row, cols - 1)));
+
+        starts.map(move |start| self.ray(start, (1, -1)))
+    }
+
+    /// The size of the grid, or `(0, 0)` if it has no cells, e.g. when it has rows but no columns.
+    fn cell_size(&self) -> (usize, usize) {
+        if self.0.is_empty() {
+            (0, 0)
+        } else {
+            self.0.size()
+        }
+    }
+
+    /// The cells from a position onwards in a direction, until the edge of the grid.
+    fn ray(&self, start: (usize, usize), offset: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(Some(start), move |&position| self.step(position, offset))
+            .map(|position| &self.0[position])
+    }
+}
+
+impl<T> From<::grid::Grid<T>> for Grid<T> {
+    fn from(grid: ::grid::Grid<T>) -> Self {
+        Self(grid)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+/// Panics if the point lies outside of the grid, like indexing by `(row, col)`.
+impl<T, C: TryInto<usize>> Index<Point<C>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<C>) -> &T {
+        &self.0[point.to_index().expect("point outside of the grid")]
+    }
+}
+
+impl<T, C: TryInto<usize>> IndexMut<Point<C>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<C>) -> &mut T {
+        &mut self.0[point.to_index().expect("point outside of the grid")]
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = ::grid::Grid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Prints one row per line, so a grid of characters reads like the input it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+    use crate::geometry::Point;
+
+    const INPUT: &str = "#.#\n..S\n#..\n..#\n";
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(grid.size(), (4, 3));
+        assert_eq!(grid[(1, 2)], 'S');
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(Grid::parse(&grid.to_string(), |c| c), grid);
+
+        let walls = Grid::parse(INPUT, |c| c == '#');
+        assert_eq!(
+            walls.map(|&wall| u8::from(wall)).to_string(),
+            "101\n000\n100\n001\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "uneven grid row")]
+    fn rejects_uneven_rows() {
+        Grid::parse("..\n...\n", |c| c);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(grid.find(|&c| c == 'S'), Some((1, 2)));
+        assert_eq!(grid.find(|&c| c == 'E'), None);
+        assert_eq!(
+            grid.find_all(|&c| c == '#').collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (2, 0), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (2, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((3, 2)).collect::<Vec<_>>(),
+            vec![(2, 2), (3, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.step((0, 1), (-1, 0)), None);
+        assert_eq!(grid.step((0, 1), (3, 1)), Some((3, 2)));
+    }
+
+    #[test]
+    fn indexes_by_point() {
+        let grid = Grid::parse(INPUT, |c| c);
+        assert_eq!(grid[Point::new(2, 1)], 'S');
+        assert_eq!(grid.get_point(Point::new(2, 1)), Some(&'S'));
+        assert_eq!(grid.get_point(Point::new(-1, 1)), None);
+        assert_eq!(grid.get_point(Point::new(1, 4)), None);
+    }
+
+    #[test]
+    fn views_lines() {
+        let grid = Grid::parse("abc\ndef\n", |c| c);
+        assert_eq!(collect(grid.iter_rows()), vec!["abc", "def"]);
+        assert_eq!(collect(grid.iter_cols()), vec!["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), vec!["c", "bf", "ae", "d"]);
+        assert_eq!(collect(grid.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+
+        let empty_grids = [
+            Grid::init(2, 0, '.'),
+            Grid::init(0, 2, '.'),
+            Grid::parse("\n\n", |c| c),
+        ];
+
+        for empty in empty_grids {
+            assert_eq!(empty.diagonals().count(), 0);
+            assert_eq!(empty.anti_diagonals().count(), 0);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,37 +7,88 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        history::Baseline, input::InputSource, perf_check::CheckConfig, runner::BenchConfig, Day,
+        Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
+            count_allocations: bool,
             submit: Option<u8>,
+            input: InputSource,
         },
         All {
+            year: Year,
             release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+            multi_input: bool,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            bench_config: BenchConfig,
+            timeout: Option<Duration>,
+            compare: Option<Baseline>,
+            check: Option<CheckConfig>,
+            memory: bool,
+            multi_input: bool,
+            resources: bool,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
+    }
+
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified. Pass `--year <year>` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Reads the `--timeout <seconds>` option that limits how long a single day may run.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        Ok(args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_secs))
+    }
+
+    /// Reads a day and checks that the event of `year` has a puzzle for it.
+    fn parse_day(day: String, year: Year) -> Result<Day, Box<dyn std::error::Error>> {
+        Ok(year.parse_day(&day)?)
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -45,37 +96,142 @@ mod args {
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                jobs: match args.opt_value_from_str("--jobs")? {
+                    Some(0) => return Err("`--jobs` expects at least one job.".into()),
+                    Some(jobs) => jobs,
+                    None => 1,
+                },
+                timeout: parse_timeout(&mut args)?,
+                multi_input: args.contains("--multi-input"),
             },
             Some("time") => {
+                let year = parse_year(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let timeout = parse_timeout(&mut args)?;
+                let compare = args.opt_value_from_str("--compare")?;
+                let memory = args.contains("--memory");
+                let multi_input = args.contains("--multi-input");
+                let resources = args.contains("--resources");
+
+                let check = if args.contains("--check") {
+                    let default = CheckConfig::default();
+                    Some(CheckConfig {
+                        tolerance: args
+                            .opt_value_from_str::<_, f64>("--tolerance")?
+                            .map_or(default.tolerance, |percent| percent / 100.0),
+                        budget: args
+                            .opt_value_from_str("--budget")?
+                            .map(Duration::from_millis),
+                    })
+                } else {
+                    None
+                };
+
+                let default = BenchConfig::default();
+                let bench_config = BenchConfig {
+                    budget: args
+                        .opt_value_from_str("--bench-time")?
+                        .map_or(default.budget, Duration::from_millis),
+                    min_samples: args
+                        .opt_value_from_str("--min-samples")?
+                        .unwrap_or(default.min_samples),
+                    max_samples: args
+                        .opt_value_from_str("--max-samples")?
+                        .unwrap_or(default.max_samples),
+                };
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| parse_day(day, year))
+                        .transpose()?,
                     store,
+                    bench_config,
+                    timeout,
+                    compare,
+                    check,
+                    memory,
+                    multi_input,
+                    resources,
+                }
+            }
+            Some("verify") => {
+                let year = parse_year(&mut args)?;
+                let timeout = parse_timeout(&mut args)?;
+                AppArguments::Verify {
+                    year,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| parse_day(day, year))
+                        .transpose()?,
+                    timeout,
+                }
+            }
+            Some("download") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Download {
+                    year,
+                    day: parse_day(args.free_from_str()?, year)?,
+                }
+            }
+            Some("read") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Read {
+                    year,
+                    day: parse_day(args.free_from_str()?, year)?,
+                }
+            }
+            Some("scaffold") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Scaffold {
+                    year,
+                    day: parse_day(args.free_from_str()?, year)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                }
+            }
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                let day = parse_day(args.free_from_str()?, year)?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let count_allocations = args.contains("--allocations");
+
+                let path: Option<String> = args.opt_value_from_str("--input")?;
+                let input = match (path, args.contains("--example")) {
+                    (Some(_), true) => {
+                        return Err("`--input` and `--example` cannot be combined.".into())
+                    }
+                    (Some(path), false) if path == "-" => InputSource::Stdin,
+                    (Some(path), false) => InputSource::File(path.into()),
+                    (None, true) => InputSource::Example(args.opt_free_from_str()?),
+                    (None, false) => InputSource::Real,
+                };
+
+                if submit.is_some() && !input.is_real() {
+                    return Err("`--submit` only works with the real puzzle input.".into());
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release,
+                    submit,
+                    dhat,
+                    count_allocations,
+                    input,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                year: parse_year(&mut args)?,
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,38 +258,68 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                jobs,
+                timeout,
+                multi_input,
+            } => all::handle(year, release, jobs, timeout, multi_input),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                bench_config,
+                timeout,
+                compare,
+                check,
+                memory,
+                multi_input,
+                resources,
+            } => time::handle(
+                year,
+                day,
+                all,
+                store,
+                &bench_config,
+                timeout,
+                compare.as_ref(),
+                check.as_ref(),
+                memory,
+                multi_input,
+                resources,
+            ),
+            AppArguments::Verify { year, day, timeout } => verify::handle(year, day, timeout),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
+            } => scaffold::handle(year, day, overwrite, download),
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
+                count_allocations,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input,
+            } => solve::handle(year, day, release, dhat, count_allocations, submit, &input),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
+            AppArguments::Today { year } => {
+                match Day::today(year) {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(year, day, false, true);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                            year.days()
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,318 @@
+/// Helpers to parse puzzle inputs without unwrapping, with errors that point at the offending line and column.
+/// A `parse` function of a day can return their [`ParseError`] and the runner prints it instead of panicking.
+use std::{any::type_name, error::Error, fmt::Display, str::FromStr};
+
+/// An input that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column in characters, starting at 1.
+    pub column: usize,
+    /// The offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error in a line, pointing at `at` if it is part of the line.
+    /// The line number is 1 until [`lines`] or [`Section::lines`] set it.
+    pub fn new(line: &str, at: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: column_of(line, at),
+            text: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The offending line with a marker under the column, e.g. for printing below the error.
+    pub fn snippet(&self) -> String {
+        format!("{}\n{}^", self.text, " ".repeat(self.column - 1))
+    }
+
+    fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The column of `part` in `line` if it is a subslice of it, otherwise 1.
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+
+    match line.get(..offset) {
+        Some(before) if offset + part.len() <= line.len() => before.chars().count() + 1,
+        _ => 1,
+    }
+}
+
+/// A block of lines of the input, separated from other blocks by blank lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// The line number of the first line in the input, starting at 1.
+    pub first_line: usize,
+}
+
+impl<'a> Section<'a> {
+    /// Parses every line of the section, see [`lines`]. Errors carry their line number in the input.
+    pub fn lines<T>(
+        &self,
+        parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        lines(self.text, parse).map_err(|e| e.shift_lines(self.first_line - 1))
+    }
+
+    /// An error about the section as a whole, pointing at its first line.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let line = self.text.lines().next().unwrap_or_default();
+        ParseError::new(line, line, message).shift_lines(self.first_line - 1)
+    }
+}
+
+/// Splits the input into the blocks of lines between blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        match (start, line.trim().is_empty()) {
+            (None, false) => start = Some((offset, index + 1)),
+            (Some((from, first_line)), true) => {
+                sections.push(Section {
+                    text: input[from..offset].trim_end(),
+                    first_line,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((from, first_line)) = start {
+        sections.push(Section {
+            text: input[from..].trim_end(),
+            first_line,
+        });
+    }
+
+    sections
+}
+
+/// Splits the input into exactly `N` sections, see [`sections`].
+/// Errors point at the first extra section, or at the last section if one is missing.
+pub fn section_array<const N: usize>(input: &str) -> Result<[Section<'_>; N], ParseError> {
+    let sections = sections(input);
+    let count = sections.len();
+
+    sections.try_into().map_err(|sections: Vec<Section>| {
+        let message = format!("expected {N} sections separated by blank lines, found {count}");
+        match sections.get(N).or(sections.last()) {
+            Some(section) => section.error(message),
+            None => ParseError::new("", "", message),
+        }
+    })
+}
+
+/// Parses every line of the input with a function, skipping blank lines.
+/// Errors carry the number of the line they occurred in.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse(line).map_err(|e| e.shift_lines(index)))
+        .collect()
+}
+
+/// Parses every line of the input as a `T`, skipping blank lines.
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    lines(input, |line| field(line, line.trim()))
+}
+
+/// Parses a part of a line as a `T`. Errors point at the part if it is a subslice of the line.
+pub fn field<T: FromStr>(line: &str, field: &str) -> Result<T, ParseError>
+where
+    T::Err: Display,
+{
+    field.parse().map_err(|e| {
+        let name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+        ParseError::new(
+            line,
+            field,
+            format!("cannot parse `{field}` as `{name}`: {e}"),
+        )
+    })
+}
+
+/// Extracts all integers of a line, e.g. `[94, -34]` from `Button A: X+94, Y=-34`.
+/// A `-` counts as sign unless it follows a letter or digit, so `1-3` gives `[1, 3]`.
+pub fn integers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut integers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        integers.push(field(line, &line[start..i])?);
+    }
+
+    Ok(integers)
+}
+
+/// Extracts exactly `N` integers of a line, see [`integers`].
+pub fn integer_array<T: FromStr, const N: usize>(line: &str) -> Result<[T; N], ParseError>
+where
+    T::Err: Display,
+{
+    let integers = integers(line)?;
+    let count = integers.len();
+
+    integers
+        .try_into()
+        .map_err(|_| ParseError::new(line, line, format!("expected {N} integers, found {count}")))
+}
+
+/// Splits a line like `x00: 1` at the separator, trimming both sides.
+pub fn key_value<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(separator)
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| {
+            ParseError::new(
+                line,
+                line,
+                format!("expected `key{separator}value`, found no `{separator}`"),
+            )
+        })
+}
+
+/// Splits a line like `x00 AND y00 -> z00` at the arrow, trimming both sides.
+pub fn arrow(line: &str) -> Result<(&str, &str), ParseError> {
+    key_value(line, "->")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(integers::<i64>("Button A: X+94, Y=-34"), Ok(vec![94, -34]));
+        assert_eq!(integers::<i32>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(integers::<u8>("1-3 a: abc"), Ok(vec![1, 3]));
+        assert_eq!(integers::<u8>("no numbers"), Ok(vec![]));
+        assert_eq!(integer_array::<u8, 2>("12|45"), Ok([12, 45]));
+    }
+
+    #[test]
+    fn points_at_invalid_integers() {
+        let error = integers::<u8>("7, 300").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(error.message.starts_with("cannot parse `300` as `u8`"));
+        assert_eq!(error.snippet(), "7, 300\n   ^");
+
+        let error = integer_array::<u8, 2>("1, 2, 3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected 2 integers, found 3"
+        );
+    }
+
+    #[test]
+    fn splits_lines() {
+        assert_eq!(key_value("x00: 1", ":"), Ok(("x00", "1")));
+        assert_eq!(arrow("x00 AND y00 -> z00"), Ok(("x00 AND y00", "z00")));
+        assert!(arrow("x00 AND y00").is_err());
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(parse_lines::<u32>("1\n\n2\n"), Ok(vec![1, 2]));
+
+        let error = parse_lines::<u32>("1\n2\nthree\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "three");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "a: 1\nb: 2\n\n\nc -> d\nc\n";
+        let sections = sections(input);
+
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    text: "a: 1\nb: 2",
+                    first_line: 1
+                },
+                Section {
+                    text: "c -> d\nc",
+                    first_line: 5
+                },
+            ]
+        );
+
+        assert_eq!(
+            sections[0].lines(|line| key_value(line, ":")),
+            Ok(vec![("a", "1"), ("b", "2")])
+        );
+
+        let error = sections[1].lines(arrow).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (6, "c"));
+        assert_eq!(sections[1].error("too short").line, 5);
+
+        assert_eq!(section_array::<2>(input), Ok([sections[0], sections[1]]));
+
+        let error = section_array::<1>(input).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (5, "c -> d"));
+        assert_eq!(
+            error.message,
+            "expected 1 sections separated by blank lines, found 2"
+        );
+
+        let error = section_array::<3>(input).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (5, "c -> d"));
+        assert_eq!(section_array::<1>("\n").unwrap_err().line, 1);
+    }
+}
@@ -0,0 +1,5 @@
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+fn main() {
+    advent_of_code::template::registry::main(SOLUTIONS);
+}
@@ -0,0 +1,259 @@
+/// Shortest path searches over any node type, for mazes and other puzzles that can be modelled as a graph.
+/// Every search keeps all optimal predecessors of a node, so all optimal paths can be reconstructed afterwards.
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use priority_queue::PriorityQueue;
+
+/// The outcome of a search: the lowest cost of every visited node and the predecessors on its optimal paths.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    /// The lowest cost to reach each visited node from the start.
+    pub costs: HashMap<N, C>,
+    /// The predecessors of each node on the optimal paths to it. The start has none.
+    pub predecessors: HashMap<N, Vec<N>>,
+    /// The goals that were reached at the lowest cost. Empty if no goal is reachable.
+    pub goals: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), zero)]),
+            predecessors: HashMap::from([(start, vec![])]),
+            goals: vec![],
+        }
+    }
+
+    /// The lowest cost to reach a goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// One optimal path from the start to a goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        let mut path = vec![self.goals.first()?.clone()];
+
+        while let Some(previous) = self.predecessors[path.last().unwrap()].first() {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// All optimal paths from the start to any goal. Their number can grow exponentially with the length.
+    pub fn all_paths(&self) -> Vec<Vec<N>> {
+        let mut paths = vec![];
+        let mut stack: Vec<Vec<N>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+
+        while let Some(path) = stack.pop() {
+            let predecessors = &self.predecessors[path.last().unwrap()];
+
+            if predecessors.is_empty() {
+                paths.push(path.into_iter().rev().collect());
+                continue;
+            }
+
+            for previous in predecessors {
+                let mut path = path.clone();
+                path.push(previous.clone());
+                stack.push(path);
+            }
+        }
+
+        paths
+    }
+
+    /// The nodes that lie on any optimal path to a goal.
+    pub fn nodes_on_paths(&self) -> HashSet<N> {
+        let mut nodes: HashSet<N> = self.goals.iter().cloned().collect();
+        let mut queue: Vec<&N> = self.goals.iter().collect();
+
+        while let Some(node) = queue.pop() {
+            for previous in &self.predecessors[node] {
+                if nodes.insert(previous.clone()) {
+                    queue.push(previous);
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// Records that `next` can be reached from `node` at `cost`. Returns if this is a new lowest cost.
+    fn relax(&mut self, node: &N, next: N, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.get(&next) {
+            Some(&known) if cost > known => false,
+            Some(&known) if cost == known => {
+                let predecessors = self.predecessors.get_mut(&next).unwrap();
+                if !predecessors.contains(node) {
+                    predecessors.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.costs.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search for graphs where every step costs the same.
+/// Visits every reachable node if `is_goal` never matches.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let cost = paths.costs[&node];
+        if paths.cost().is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if is_goal(&node) {
+            paths.goals.push(node);
+            continue;
+        }
+
+        for next in neighbours(&node) {
+            if paths.relax(&node, next.clone(), cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm for graphs with non-negative step costs.
+/// `neighbours` returns the nodes reachable from a node with the cost of each step.
+/// Visits every reachable node if `is_goal` never matches.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search, which explores nodes closer to a goal first.
+/// The `heuristic` estimates the remaining cost to a goal and must never overestimate it.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut queue = PriorityQueue::new();
+    queue.push(start.clone(), Reverse(heuristic(&start)));
+
+    while let Some((node, Reverse(estimate))) = queue.pop() {
+        if paths.cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+
+        if is_goal(&node) {
+            paths.goals.push(node);
+            continue;
+        }
+
+        let cost = paths.costs[&node];
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&node, next.clone(), next_cost) {
+                let estimate = next_cost + heuristic(&next);
+                queue.push_increase(next, Reverse(estimate));
+            }
+        }
+    }
+
+    paths
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+
+    /// A 3x3 open grid, moving right or down only.
+    fn right_or_down(&(x, y): &(u8, u8)) -> Vec<(u8, u8)> {
+        [(x + 1, y), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| x < 3 && y < 3)
+            .collect()
+    }
+
+    #[test]
+    fn finds_all_shortest_paths() {
+        let paths = bfs((0, 0), right_or_down, |&node| node == (2, 2));
+
+        assert_eq!(paths.cost(), Some(4));
+        assert_eq!(paths.path().unwrap().len(), 5);
+        // choose 2 of 4 steps to go right.
+        assert_eq!(paths.all_paths().len(), 6);
+        assert_eq!(paths.nodes_on_paths().len(), 9);
+    }
+
+    #[test]
+    fn visits_everything_without_goal() {
+        let paths = bfs((0, 0), right_or_down, |_| false);
+
+        assert_eq!(paths.cost(), None);
+        assert_eq!(paths.path(), None);
+        assert_eq!(paths.costs.len(), 9);
+        assert_eq!(paths.costs[&(2, 1)], 3);
+    }
+
+    #[test]
+    fn prefers_cheaper_steps() {
+        // going through 1 costs 1 + 1, going directly costs 5, going through 2 costs 1 + 1 as well.
+        let edges = |&node: &u8| match node {
+            0 => vec![(1, 1), (3, 5), (2, 1)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        let paths = dijkstra(0, edges, |&node| node == 3);
+        assert_eq!(paths.cost(), Some(2));
+        assert_eq!(paths.nodes_on_paths().len(), 4);
+
+        let mut all_paths = paths.all_paths();
+        all_paths.sort();
+        assert_eq!(all_paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+
+        let estimate = |&node: &u8| if node == 3 { 0 } else { 1 };
+        let paths = astar(0, edges, estimate, |&node| node == 3);
+        assert_eq!(paths.cost(), Some(2));
+        assert_eq!(paths.all_paths().len(), 2);
+    }
+}
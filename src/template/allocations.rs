@@ -0,0 +1,117 @@
+/// Counts heap allocations with a global allocator, enabled with the `count-allocations` feature.
+/// Much cheaper than the heap profiler of the `dhat-heap` feature, but only keeps totals.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::template::runner::MemoryStats;
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Forwards to the system allocator and counts every allocation.
+/// A reallocation counts as a new allocation of the new size, like the heap profiler does.
+pub struct CountingAlloc;
+
+fn record_alloc(size: usize) {
+    let size = size as u64;
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    CURRENT_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The counters at the start of a measurement.
+pub struct Measurement {
+    allocations: u64,
+    allocated_bytes: u64,
+    current_bytes: u64,
+}
+
+impl Measurement {
+    /// Starts a measurement. Resets the high-water mark to the memory that is currently allocated.
+    pub fn start() -> Self {
+        let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            current_bytes,
+        }
+    }
+
+    /// The allocations since the start of the measurement.
+    /// The peak only counts memory that was allocated on top of what was allocated at the start.
+    pub fn stop(&self) -> MemoryStats {
+        MemoryStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            total_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.allocated_bytes,
+            peak_bytes: PEAK_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.current_bytes),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::hint::black_box;
+
+    use super::Measurement;
+
+    #[test]
+    fn counts_allocations() {
+        let measurement = Measurement::start();
+        let buffer: Vec<u8> = black_box(Vec::with_capacity(4096));
+        drop(buffer);
+        let stats = measurement.stop();
+
+        // tests running in parallel may allocate as well.
+        assert!(stats.allocations >= 1);
+        assert!(stats.total_bytes >= 4096);
+        assert!(stats.peak_bytes >= 4096);
+    }
+}
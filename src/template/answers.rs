@@ -0,0 +1,256 @@
+/// Keeps the known answers of each part for the real puzzle inputs.
+/// Accepted submissions are added automatically, answers can also be filled in by hand.
+use std::{collections::BTreeMap, collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// The known answers, keyed by year, day, input and part.
+/// Can be serialized from / to JSON, e.g. `{ "2024": { "01": { "part_1": "11", "part_2": "31" } } }`.
+/// Answers for an additional input in `inputs/<day>/<name>.txt` are keyed by `<day>/<name>`, e.g. `"01/alice"`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    /// The input is `None` for the real puzzle input.
+    pub data: BTreeMap<(Year, Day, Option<String>, u8), String>,
+}
+
+impl Answers {
+    /// Dehydrate the answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the answers from a JSON file. If not present, returns no answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(contents) => Answers::try_from(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn get(&self, year: Year, day: Day, input: Option<&str>, part: u8) -> Option<&str> {
+        self.data
+            .get(&(year, day, input.map(Into::into), part))
+            .map(String::as_str)
+    }
+
+    /// Records the answer of a part, replacing a previous answer.
+    pub fn insert(&mut self, year: Year, day: Day, input: Option<&str>, part: u8, answer: &str) {
+        self.data
+            .insert((year, day, input.map(Into::into), part), answer.into());
+    }
+}
+
+/// How the output of a part compares to its known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verification {
+    Pass,
+    /// The part gave a different answer or none at all.
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The part gave an answer, but there is no known answer to compare it to.
+    Unknown,
+}
+
+impl Verification {
+    /// Compares an answer to the known answer. Returns `None` if neither exists.
+    pub fn new(expected: Option<&str>, actual: Option<&str>) -> Option<Self> {
+        match (expected, actual) {
+            (Some(expected), Some(actual)) if expected == actual => Some(Verification::Pass),
+            (Some(expected), actual) => Some(Verification::Fail {
+                expected: expected.into(),
+                actual: actual.map(Into::into),
+            }),
+            (None, Some(_)) => Some(Verification::Unknown),
+            (None, None) => None,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut years: HashMap<String, JsonValue> = HashMap::new();
+
+        for ((year, day, input, part), answer) in &value.data {
+            let JsonValue::Object(days) = years
+                .entry(year.to_string())
+                .or_insert_with(|| JsonValue::Object(HashMap::new()))
+            else {
+                unreachable!();
+            };
+
+            let day_key = match input {
+                Some(input) => format!("{day}/{input}"),
+                None => day.to_string(),
+            };
+
+            let JsonValue::Object(parts) = days
+                .entry(day_key)
+                .or_insert_with(|| JsonValue::Object(HashMap::new()))
+            else {
+                unreachable!();
+            };
+
+            parts.insert(format!("part_{part}"), JsonValue::String(answer.clone()));
+        }
+
+        JsonValue::Object(years)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let years = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut answers = Answers::default();
+
+        for (year_key, days) in years {
+            let year = year_key
+                .parse::<Year>()
+                .map_err(|_| format!("expected `{year_key}` to be a year."))?;
+
+            let days = days
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected json.{year_key} to be an object."))?;
+
+            for (day_key, parts) in days {
+                let (day, input) = match day_key.split_once('/') {
+                    Some((day, input)) => (day, Some(input)),
+                    None => (day_key.as_str(), None),
+                };
+
+                let day = year
+                    .parse_day(day)
+                    .map_err(|_| format!("expected `{day_key}` to be a day of {year}."))?;
+
+                let parts = parts.get::<HashMap<String, JsonValue>>().ok_or(format!(
+                    "expected json.{year_key}.{day_key} to be an object."
+                ))?;
+
+                for (part_key, answer) in parts {
+                    let part = match part_key.as_str() {
+                        "part_1" => 1,
+                        "part_2" => 2,
+                        _ => return Err(format!("expected `{part_key}` to be part_1 or part_2.")),
+                    };
+
+                    // answers filled in by hand may be plain numbers.
+                    let answer = match answer {
+                        JsonValue::String(answer) => answer.clone(),
+                        JsonValue::Number(number) => number.to_string(),
+                        _ => {
+                            return Err(format!(
+                                "expected json.{year_key}.{day_key}.{part_key} to be a string or number."
+                            ))
+                        }
+                    };
+
+                    answers.insert(year, day, input, part, &answer);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verification};
+    use crate::{day, year};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_answers() {
+        let mut answers = Answers::default();
+        answers.insert(year!(2024), day!(1), None, 1, "11");
+        answers.insert(year!(2024), day!(1), None, 2, "31");
+        answers.insert(year!(2024), day!(1), Some("alice"), 1, "12");
+        answers.insert(year!(2024), day!(25), None, 1, "abc");
+
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn reads_answers_filled_in_by_hand() {
+        let json = r#"{ "2024": { "07": { "part_1": 3749, "part_2": "11387" } } }"#;
+        let answers = Answers::try_from(json.to_string()).unwrap();
+
+        assert_eq!(answers.get(year!(2024), day!(7), None, 1), Some("3749"));
+        assert_eq!(answers.get(year!(2024), day!(7), None, 2), Some("11387"));
+        assert_eq!(answers.get(year!(2024), day!(8), None, 1), None);
+    }
+
+    #[test]
+    fn reads_answers_of_additional_inputs() {
+        let json = r#"{ "2024": { "07": { "part_1": "1" }, "07/alice": { "part_1": "2" } } }"#;
+        let answers = Answers::try_from(json.to_string()).unwrap();
+
+        assert_eq!(answers.get(year!(2024), day!(7), None, 1), Some("1"));
+        assert_eq!(
+            answers.get(year!(2024), day!(7), Some("alice"), 1),
+            Some("2")
+        );
+        assert_eq!(answers.get(year!(2024), day!(7), Some("bob"), 1), None);
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        let json = r#"{ "2024": { "07": { "part_3": "1" } } }"#;
+        assert!(Answers::try_from(json.to_string()).is_err());
+    }
+
+    #[test]
+    fn rejects_days_outside_the_event() {
+        let json = r#"{ "2025": { "13": { "part_1": "1" } } }"#;
+        assert_eq!(
+            Answers::try_from(json.to_string()),
+            Err("expected `13` to be a day of 2025.".into())
+        );
+    }
+
+    #[test]
+    fn verifies_answers() {
+        assert_eq!(
+            Verification::new(Some("11"), Some("11")),
+            Some(Verification::Pass)
+        );
+        assert_eq!(
+            Verification::new(Some("11"), Some("12")),
+            Some(Verification::Fail {
+                expected: "11".into(),
+                actual: Some("12".into())
+            })
+        );
+        assert_eq!(
+            Verification::new(Some("11"), None),
+            Some(Verification::Fail {
+                expected: "11".into(),
+                actual: None
+            })
+        );
+        assert_eq!(
+            Verification::new(None, Some("11")),
+            Some(Verification::Unknown)
+        );
+        assert_eq!(Verification::new(None, None), None);
+    }
+}
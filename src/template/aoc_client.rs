@@ -0,0 +1,523 @@
+/// Built-in client for the Advent of Code website.
+/// Fetches inputs and puzzle descriptions and posts answers without relying on external tools.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{get_data_dir, Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    SessionExpired,
+    PuzzleNotReleased,
+    HttpStatus(u16),
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `ADVENT_OF_CODE_SESSION` or create `~/.adventofcode.session`."
+            ),
+            AocClientError::SessionExpired => {
+                write!(f, "the session cookie is invalid or has expired.")
+            }
+            AocClientError::PuzzleNotReleased => write!(f, "this puzzle has not been released yet."),
+            AocClientError::HttpStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// An authenticated connection to an Advent of Code server for a single event year.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Creates a client for a year from the environment.
+    /// The base URL can be overridden with `AOC_BASE_URL`, e.g. to point it to a local server.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn get_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("/{}/day/{}/input", self.year, day.into_inner()))
+    }
+
+    /// Fetches the puzzle description for a day, converted to markdown.
+    pub fn get_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{}/day/{}", self.year, day.into_inner()))?;
+
+        if is_logged_out(&html) {
+            return Err(AocClientError::SessionExpired);
+        }
+
+        Ok(articles_to_markdown(&html))
+    }
+
+    /// Submits an answer for one part of a day and returns the server's reply as markdown.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url,
+            self.year,
+            day.into_inner()
+        );
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let html = read_response(response)?;
+
+        if is_logged_out(&html) {
+            return Err(AocClientError::SessionExpired);
+        }
+
+        Ok(articles_to_markdown(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::Transport(e.to_string())),
+        // unreleased puzzles respond with a 404.
+        Err(ureq::Error::Status(404, _)) => Err(AocClientError::PuzzleNotReleased),
+        // missing cookies yield a 400 ("please log in"), invalid ones a 500.
+        Err(ureq::Error::Status(400 | 500, _)) => Err(AocClientError::SessionExpired),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::HttpStatus(status)),
+        Err(e) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+/// Pages requested with an invalid session still render, but contain a login link.
+fn is_logged_out(html: &str) -> bool {
+    html.contains("/auth/login\"")
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn read(year: Year, day: Day) -> Result<String, AocClientError> {
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let client = AocClient::from_env(year)?;
+    println!("Fetching puzzle for day {day}, {year}...");
+
+    let puzzle = client.get_puzzle(day)?;
+    if let Some(dir) = Path::new(&puzzle_path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&puzzle_path, &puzzle)?;
+    println!("\n{puzzle}");
+
+    Ok(puzzle)
+}
+
+pub fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let client = AocClient::from_env(year)?;
+    let puzzle = client.get_puzzle(day)?;
+    let input = client.get_input(day)?;
+
+    for path in [&input_path, &puzzle_path] {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    fs::write(&input_path, input)?;
+    fs::write(&puzzle_path, puzzle)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<String, AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let response = client.submit(day, part, result)?;
+    println!("{response}");
+    Ok(response)
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    let path = get_data_dir(year).join("inputs").join(format!("{day}.txt"));
+    path.to_string_lossy().into_owned()
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> String {
+    let path = get_data_dir(year).join("puzzles").join(format!("{day}.md"));
+    path.to_string_lossy().into_owned()
+}
+
+/// Reads the session cookie from `ADVENT_OF_CODE_SESSION` or one of the files aoc-cli uses.
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map_or_else(|| PathBuf::from(&home).join(".config"), PathBuf::from);
+
+    [
+        PathBuf::from(&home).join(".adventofcode.session"),
+        config_dir.join("adventofcode.session"),
+    ]
+    .iter()
+    .find_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .filter(|session| !session.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts every `<article>` of a page to markdown, falling back to `<main>` if there is none.
+fn articles_to_markdown(html: &str) -> String {
+    let mut blocks = extract_elements(html, "article");
+
+    if blocks.is_empty() {
+        blocks = extract_elements(html, "main");
+    }
+
+    blocks
+        .iter()
+        .map(|block| html_to_markdown(block))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn extract_elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find(&close)
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+        elements.push(&rest[content_start..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    elements
+}
+
+/// A small converter for the subset of HTML used in puzzle descriptions.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            out.push_str(&decode_entities(rest));
+            break;
+        };
+
+        let text = &rest[..tag_start];
+        if in_pre {
+            out.push_str(&decode_entities(text));
+        } else {
+            out.push_str(&decode_entities(&text.replace('\n', " ")));
+        }
+
+        let Some(tag_end) = rest[tag_start..].find('>').map(|i| tag_start + i) else {
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        // inside of code blocks, only the raw text is kept.
+        if in_pre && name != "pre" {
+            continue;
+        }
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("\n\n## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("p" | "ul", false) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("\n- "),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("\n\n```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            ("br", _) => out.push('\n'),
+            ("a", false) => {
+                links.push(get_attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    normalize_blank_lines(&out)
+}
+
+fn get_attribute(tag: &str, attribute: &str) -> Option<String> {
+    let needle = format!("{attribute}=\"");
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Trims trailing whitespace and collapses runs of blank lines.
+fn normalize_blank_lines(s: &str) -> String {
+    let mut lines: Vec<&str> = vec![];
+
+    for line in s.lines().map(str::trim_end) {
+        if line.is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{html_to_markdown, AocClient, AocClientError};
+    use crate::{day, year};
+
+    /// Starts a server on a random port that answers a single request with `status` and `body`.
+    /// Returns its base URL and a receiver for the raw request it got.
+    fn serve_once(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (url, rx) = serve_once(200, "1 2\n3 4\n");
+        let client = AocClient::new(&url, "abc\n", year!(2024));
+        assert_eq!(client.get_input(day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, _) = serve_once(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>For <em>example</em>:</p><pre><code>3   4\n<em>4</em>   3\n</code></pre></article></main>",
+        );
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert_eq!(
+            client.get_puzzle(day!(1)).unwrap(),
+            "## --- Day 1 ---\n\nFor *example*:\n\n```\n3   4\n4   3\n```"
+        );
+    }
+
+    #[test]
+    fn posts_answers() {
+        let (url, rx) = serve_once(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert_eq!(
+            client.submit(day!(3), 2, "42").unwrap(),
+            "That's the right answer!"
+        );
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn detects_unreleased_puzzles() {
+        let (url, _) = serve_once(404, "Please don't repeatedly request this endpoint.");
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert!(matches!(
+            client.get_input(day!(25)),
+            Err(AocClientError::PuzzleNotReleased)
+        ));
+    }
+
+    #[test]
+    fn detects_expired_sessions() {
+        let (url, _) = serve_once(400, "Puzzle inputs differ by user. Please log in.");
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert!(matches!(
+            client.get_input(day!(1)),
+            Err(AocClientError::SessionExpired)
+        ));
+
+        let (url, _) = serve_once(
+            200,
+            "<a href=\"/2024/auth/login\">[Log In]</a><main><article></article></main>",
+        );
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert!(matches!(
+            client.get_puzzle(day!(1)),
+            Err(AocClientError::SessionExpired)
+        ));
+    }
+
+    #[test]
+    fn reports_other_http_errors() {
+        let (url, _) = serve_once(503, "");
+        let client = AocClient::new(&url, "abc", year!(2024));
+        assert!(matches!(
+            client.get_input(day!(1)),
+            Err(AocClientError::HttpStatus(503))
+        ));
+    }
+
+    #[test]
+    fn converts_links_and_lists() {
+        assert_eq!(
+            html_to_markdown(
+                "<p>See <a href=\"/2024/day/1\">here</a> &amp; <code>x &lt; 3</code>.</p><ul><li>one</li><li>two</li></ul>"
+            ),
+            "See [here](/2024/day/1) & `x < 3`.\n\n- one\n- two"
+        );
+    }
+}
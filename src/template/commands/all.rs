@@ -1,5 +1,30 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::{process, time::Duration};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    run_multi::{run_multi, DEFAULT_TIMEOUT},
+    Year,
+};
+
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    multi_input: bool,
+) {
+    let run = run_multi(
+        year,
+        &all_days(year).collect(),
+        is_release,
+        None,
+        jobs,
+        timeout.unwrap_or(DEFAULT_TIMEOUT),
+        multi_input,
+        false,
+    );
+
+    if run.has_failures() {
+        process::exit(1);
+    }
 }
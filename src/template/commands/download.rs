@@ -1,14 +1,29 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::examples::Examples;
+use crate::template::{aoc_client, Day, Year};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+pub fn handle(year: Year, day: Day) {
+    if let Err(e) = aoc_client::download(year, day) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
-    }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let Some(examples) = Examples::read_from_puzzle(year, day) else {
+        return;
     };
+
+    match examples.store(year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("🎄 Extracted example to \"{}\".", path.display());
+            }
+        }
+        Err(e) => eprintln!("failed to store examples: {e}"),
+    }
+
+    for (part, answer) in examples.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            println!("🎄 Detected example answer for part {}: {answer}", part + 1);
+        }
+    }
 }
@@ -1,10 +1,12 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
+use crate::template::commands::download;
+use crate::template::examples::Examples;
+use crate::template::{get_bin_name, get_data_dir, Day, Year};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
@@ -19,18 +21,83 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates the data folders and empty input and example files of a day.
+/// Files that already exist, e.g. because the day was downloaded before, are kept.
+fn create_data_files(year: Year, day: Day) -> Result<(), String> {
+    let data_dir = get_data_dir(year);
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        fs::create_dir_all(data_dir.join(folder))
+            .map_err(|e| format!("Failed to create data directory: {e}"))?;
+    }
+
+    for (name, folder) in [("input", "inputs"), ("example", "examples")] {
+        let path = format!("{}/{folder}/{day}.txt", data_dir.display());
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => println!("Created empty {name} file \"{path}\""),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                println!("Kept existing {name} file \"{path}\"");
+            }
+            Err(e) => return Err(format!("Failed to create {name} file: {e}")),
+        }
+    }
+
+    Ok(())
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Renders the expected answer of a part for the generated test.
+fn template_answer(examples: &Examples, part: u8) -> String {
+    examples
+        .template_answer(part)
+        .map_or("None".into(), |answer| format!("Some({answer})"))
+}
+
+/// Renders the example the generated part two test reads and the answer it expects.
+/// Part two often brings an example of its own, which is stored as `DD-2.txt` and so on.
+fn part_two_test(examples: &Examples) -> (String, String) {
+    match examples.answer_inputs[1] {
+        Some(0) => (
+            r#"read_file("examples", YEAR, DAY)"#.into(),
+            template_answer(examples, 2),
+        ),
+        Some(index) => (
+            format!(r#"read_file_part("examples", YEAR, DAY, {})"#, index + 1),
+            template_answer(examples, 2),
+        ),
+        None => (r#"read_file("examples", YEAR, DAY)"#.into(), "None".into()),
+    }
+}
+
+fn render_module(year: Year, day: Day, examples: &Examples) -> String {
+    let (part_two_input, part_two_answer) = part_two_test(examples);
+
+    MODULE_TEMPLATE
+        .replace("%YEAR%", &year.to_string())
+        .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+        .replace("%PART_ONE_ANSWER%", &template_answer(examples, 1))
+        .replace("%PART_TWO_INPUT%", &part_two_input)
+        .replace("%PART_TWO_ANSWER%", &part_two_answer)
+}
+
+/// Renders the module again with the example answers of the downloaded puzzle, if there are any.
+fn fill_in_answers(year: Year, day: Day, module_path: &str) {
+    let Some(examples) = Examples::read_from_puzzle(year, day) else {
+        return;
+    };
+
+    if examples.answers.iter().all(Option::is_none) {
+        return;
+    }
+
+    match fs::write(module_path, render_module(year, day, &examples)) {
+        Ok(()) => println!("Filled in example answers in \"{module_path}\""),
+        Err(e) => eprintln!("Failed to fill in example answers: {e}"),
+    }
+}
+
+pub fn handle(year: Year, day: Day, overwrite: bool, download: bool) {
+    let module_path = format!("src/bin/{}.rs", get_bin_name(year, day));
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +107,9 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    let examples = Examples::read_from_puzzle(year, day).unwrap_or_default();
+
+    match file.write_all(render_module(year, day, &examples).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +119,105 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    if let Err(e) = create_data_files(year, day) {
+        eprintln!("{e}");
+        process::exit(1);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    // the module exists before downloading, so a failed download still leaves a day to work on.
+    if download {
+        download::handle(year, day);
+        fill_in_answers(year, day, &module_path);
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{create_data_files, render_module};
+    use crate::template::examples::Examples;
+    use crate::template::get_data_dir;
+    use crate::{day, year};
+
+    const PUZZLE: &str = "## --- Day 3: Mull It Over ---
+
+For example, consider the following section of corrupted memory:
+
+```
+xmul(2,4)%&mul[3,7]
+mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+```
+
+Adding up the result of each multiplication produces *`161`*.
+
+## --- Part Two ---
+
+For example:
+
+```
+xmul(2,4)&mul[3,7]!^don't()
+_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+```
+
+This time, the sum of the results is *`48`*.";
+
+    #[test]
+    fn reads_the_part_two_example_in_its_test() {
+        let module = render_module(year!(2024), day!(3), &Examples::parse(PUZZLE));
+
+        assert!(module.contains(
+            "part_one(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, Some(161));"
+        ));
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 2));\n        assert_eq!(result, Some(48));"
+        ));
+    }
+
+    #[test]
+    fn reads_the_first_example_when_part_two_reuses_it() {
+        let puzzle = "For example:\n\n```\n1\n2\n```\n\nThe sum is *`3`*.\n\n## --- Part Two ---\n\nThe product is *`2`*.";
+        let module = render_module(year!(2024), day!(3), &Examples::parse(puzzle));
+
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, Some(2));"
+        ));
+    }
+
+    #[test]
+    fn leaves_answers_without_an_example_open() {
+        let module = render_module(
+            year!(2024),
+            day!(3),
+            &Examples::parse("The answer is `*7*`."),
+        );
+
+        assert!(module.contains("assert_eq!(result, Some(7));"));
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_file(\"examples\", YEAR, DAY));\n        assert_eq!(result, None);"
+        ));
+    }
+
+    #[test]
+    fn keeps_downloaded_examples() {
+        // a year without an event, so the test does not touch real data.
+        let year = year!(9999);
+        let data_dir = get_data_dir(year);
+
+        let examples = Examples::parse(PUZZLE);
+        examples.store(year, day!(3)).unwrap();
+        create_data_files(year, day!(3)).unwrap();
+
+        let example = fs::read_to_string(data_dir.join("examples/03.txt"));
+        let input = fs::read_to_string(data_dir.join("inputs/03.txt"));
+        fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(example.unwrap(), examples.inputs[0]);
+        assert_eq!(input.unwrap(), "");
+    }
 }
@@ -1,19 +1,38 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process, time::Duration};
 
-use crate::template::run_multi::run_multi;
+use crate::template::history::{compare, print_comparison, Baseline, TimingHistory, TimingRun};
+use crate::template::perf_check::{self, CheckConfig};
+use crate::template::run_multi::{run_memory, run_multi, DEFAULT_TIMEOUT};
+use crate::template::runner::BenchConfig;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    bench_config: &BenchConfig,
+    timeout: Option<Duration>,
+    compare_to: Option<&Baseline>,
+    check: Option<&CheckConfig>,
+    memory: bool,
+    multi_input: bool,
+    resources: bool,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
+            } else if check.is_some() {
+                // when checking, re-run the days that have a stored baseline.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,14 +40,60 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run = run_multi(
+        year,
+        &days_to_run,
+        true,
+        Some(bench_config),
+        1,
+        timeout.unwrap_or(DEFAULT_TIMEOUT),
+        multi_input,
+        resources,
+    );
+    let mut timings = run.timings.clone().unwrap();
+
+    // heap profiling slows down allocations, so it runs separately from the timed run.
+    if memory {
+        let reports = run_memory(year, &days_to_run, timeout.unwrap_or(DEFAULT_TIMEOUT));
+
+        for report in &reports {
+            if let Some(timing) = timings.data.iter_mut().find(|t| t.day == report.day) {
+                timing.set_memory(report.part, report.memory.clone());
+            }
+        }
+    }
+
+    let timings = &timings;
+    let timing_run = TimingRun::new(timings.clone(), run.is_release);
+
+    if let Some(baseline) = compare_to {
+        // compare before appending, so the current run is not its own baseline.
+        let history = TimingHistory::read_from_file(year);
+        match history.baseline(baseline, &timing_run.profile) {
+            Some(medians) => print_comparison(baseline, &compare(&medians, timings)),
+            None => eprintln!("\nNo stored run matches `--compare`, nothing to compare."),
+        }
+    }
+
+    // runs that benched nothing, e.g. because every day failed, would hide earlier runs.
+    if timing_run.has_benched_parts() {
+        if let Err(e) = TimingHistory::append(year, &timing_run) {
+            eprintln!("Failed to append run to timing history: {e}");
+        }
+    }
+
+    let check_passed = check.is_none_or(|config| {
+        let report = perf_check::check(&stored_timings, timings, config);
+        perf_check::print_report(&report, config);
+        report.passed()
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        let merged_timings = stored_timings.merge(timings);
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(Timings::read_all_years()) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +102,8 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if run.has_failures() || !check_passed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,100 @@
+use std::{collections::HashSet, process, time::Duration};
+
+use crate::template::answers::{Answers, Verification};
+use crate::template::ledger::Ledger;
+use crate::template::run_multi::{run_multi, DEFAULT_TIMEOUT};
+use crate::template::{
+    all_days, Day, Year, ANSI_BOLD, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET,
+};
+
+pub fn handle(year: Year, day: Option<Day>, timeout: Option<Duration>) {
+    let answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("failed to read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    // accepted submissions that were made before answers were recorded.
+    let ledger = match Ledger::read_from_file() {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("failed to read submissions: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days_to_run: HashSet<Day> =
+        day.map_or_else(|| all_days(year).collect(), |day| [day].into());
+
+    let run = run_multi(
+        year,
+        &days_to_run,
+        true,
+        None,
+        1,
+        timeout.unwrap_or(DEFAULT_TIMEOUT),
+        true,
+        false,
+    );
+
+    println!("\n{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!("------------");
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for day in all_days(year).filter(|day| days_to_run.contains(day)) {
+        // the real input first, then the additional inputs in the order they ran.
+        let inputs = std::iter::once(None).chain(
+            run.inputs
+                .iter()
+                .filter(|input| input.day == day)
+                .map(|input| Some(input.name.as_str())),
+        );
+
+        for input in inputs {
+            for part in 1..=2 {
+                // the ledger only knows answers for the real input.
+                let expected = answers
+                    .get(year, day, input, part)
+                    .or_else(|| ledger.solution(year, day, part).filter(|_| input.is_none()));
+
+                let actual = run.answer(day, input, part);
+
+                let Some(verification) = Verification::new(expected, actual) else {
+                    continue;
+                };
+
+                let label = match input {
+                    Some(name) => format!("Day {day} Part {part} {name}"),
+                    None => format!("Day {day} Part {part}"),
+                };
+
+                match verification {
+                    Verification::Pass => {
+                        passed += 1;
+                        println!("{label}  {ANSI_GREEN}pass{ANSI_RESET}");
+                    }
+                    Verification::Fail { expected, actual } => {
+                        failed += 1;
+                        let actual = actual.map_or("no answer".into(), |a| format!("`{a}`"));
+                        println!(
+                            "{label}  {ANSI_RED}fail{ANSI_RESET}: expected `{expected}`, got {actual}"
+                        );
+                    }
+                    Verification::Unknown => {
+                        unknown += 1;
+                        println!("{label}  {ANSI_ITALIC}no known answer{ANSI_RESET}");
+                    }
+                }
+            }
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {unknown} without known answer");
+
+    if failed > 0 || run.has_failures() {
+        process::exit(1);
+    }
+}
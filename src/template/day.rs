@@ -2,13 +2,16 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A valid day number of advent (i.e. an integer in range 1 to [`Year::MAX_DAYS`]).
+/// Not every event has that many days, see [`Year::days`] and [`Day::new_in`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -22,15 +25,21 @@ const SERVER_UTC_OFFSET: i32 = -5;
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
+    /// Creates a [`Day`] from the provided value if any event has a puzzle for it,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
+        if day == 0 || day > Year::MAX_DAYS {
             return None;
         }
         Some(Self(day))
     }
 
+    /// Creates a [`Day`] from the provided value if the event of `year` has a puzzle for it,
+    /// returns [`None`] otherwise.
+    pub fn new_in(day: u8, year: Year) -> Option<Self> {
+        Self::new(day).filter(|day| year.has_day(*day))
+    }
+
     // Not part of the public API
     #[doc(hidden)]
     pub const fn __new_unchecked(day: u8) -> Self {
@@ -45,11 +54,12 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
+    /// Returns the current day if it's a day of december that has a puzzle in the event of `year`,
+    /// `None` otherwise.
+    pub fn today(year: Year) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
+        if today.month() == 12 && today.day() <= u32::from(year.days()) {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
@@ -80,40 +90,59 @@ impl PartialOrd<u8> for Day {
 impl FromStr for Day {
     type Err = DayFromStrError;
 
+    /// Parses a day that any event has a puzzle for. Use [`Year::parse_day`] to check it against a year.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let day = s.parse().map_err(|_| DayFromStrError)?;
-        Self::new(day).ok_or(DayFromStrError)
+        let error = DayFromStrError { year: None };
+        let day = s.parse().map_err(|_| error)?;
+        Self::new(day).ok_or(error)
     }
 }
 
 /// An error which can be returned when parsing a [`Day`].
-#[derive(Debug)]
-pub struct DayFromStrError;
+#[derive(Debug, Clone, Copy)]
+pub struct DayFromStrError {
+    /// The year the day was checked against, if any.
+    pub(crate) year: Option<Year>,
+}
 
 impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        match self.year {
+            Some(year) => write!(
+                f,
+                "expecting a day number between 1 and {}, the event of {year} has no puzzle for later days",
+                year.days()
+            ),
+            None => write!(
+                f,
+                "expecting a day number between 1 and {}",
+                Year::MAX_DAYS
+            ),
+        }
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent of a year, from the 1st to its last puzzle.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent of a year, from the 1st to its last puzzle.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.days(),
+        }
     }
 }
 
@@ -121,10 +150,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -135,15 +164,30 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+///
+/// The optional second parameter is a [`Year`](crate::template::Year) constant.
+/// If passed, the day is also checked against the number of puzzles of that year's event.
+/// Without it, the day is year-agnostic like [`Day::new`] and only needs to exist in some event.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
         const _ASSERT: () = assert!(
-            $day != 0 && $day <= 25,
+            $day != 0 && $day <= $crate::template::Year::MAX_DAYS,
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, no event has a puzzle for it"
+            ),
+        );
+        $crate::template::Day::__new_unchecked($day)
+    }};
+    ($day:expr, $year:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $year.days(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, the event of this year does not have a puzzle for it"
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -155,10 +199,30 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
+    use crate::year;
+
+    #[test]
+    fn checks_days_against_years() {
+        assert_eq!(Day::new(25), Some(Day(25)));
+        assert_eq!(Day::new(26), None);
+        assert_eq!(Day::new_in(25, year!(2024)), Some(Day(25)));
+        assert_eq!(Day::new_in(13, year!(2025)), None);
+        assert_eq!(Day::new_in(12, year!(2025)), Some(Day(12)));
+
+        assert_eq!(year!(2025).parse_day("12").ok(), Some(Day(12)));
+        assert_eq!(
+            year!(2025).parse_day("13").unwrap_err().to_string(),
+            "expecting a day number between 1 and 12, the event of 2025 has no puzzle for later days"
+        );
+        assert_eq!(
+            "26".parse::<Day>().unwrap_err().to_string(),
+            "expecting a day number between 1 and 25"
+        );
+    }
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +251,14 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_shorter_events() {
+        let days: Vec<Day> = all_days(year!(2025)).collect();
+        assert_eq!(days.len(), 12);
+        assert_eq!(days.first(), Some(&Day(1)));
+        assert_eq!(days.last(), Some(&Day(12)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,309 @@
+/// Extracts example inputs and their expected answers from a downloaded puzzle description.
+/// Puzzles introduce their examples in prose and mark answers as emphasized code, so this is a heuristic.
+use std::{fs, io, path::PathBuf};
+
+use crate::template::{get_data_dir, Day, Year};
+
+/// The examples and expected answers found in a puzzle description.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    /// Distinct example inputs in order of appearance.
+    pub inputs: Vec<String>,
+    /// The last emphasized answer of each part, usually the answer for the example.
+    pub answers: [Option<String>; 2],
+    /// The example each answer follows, as an index into `inputs`.
+    pub answer_inputs: [Option<usize>; 2],
+}
+
+impl Examples {
+    /// Parses a puzzle description in the markdown format the AoC client writes.
+    pub fn parse(markdown: &str) -> Self {
+        let (inputs, prose) = scan(markdown);
+        let mut examples = Examples {
+            inputs,
+            ..Examples::default()
+        };
+
+        for part in 0..2 {
+            // the answer is in the last stretch of prose of the part that has one.
+            let answer =
+                prose.iter().rev().filter(|p| p.part == part).find_map(|p| {
+                    last_emphasized_code(&p.text).map(|value| (value, p.after_input))
+                });
+
+            if let Some((value, after_input)) = answer {
+                examples.answers[part] = Some(value);
+                examples.answer_inputs[part] = after_input;
+            }
+        }
+
+        examples
+    }
+
+    /// Parses the downloaded puzzle description of a day, if there is one.
+    pub fn read_from_puzzle(year: Year, day: Day) -> Option<Self> {
+        let path = get_data_dir(year).join("puzzles").join(format!("{day}.md"));
+        fs::read_to_string(path)
+            .ok()
+            .map(|markdown| Self::parse(&markdown))
+    }
+
+    /// Returns the expected answer of a part if it fits the `u32` of the solution template.
+    pub fn template_answer(&self, part: u8) -> Option<u32> {
+        self.answers
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_ref()?
+            .parse()
+            .ok()
+    }
+
+    /// Writes the inputs as `DD.txt`, `DD-2.txt` and so on to the examples folder.
+    /// Files that already have content are left untouched. Returns the paths that were written.
+    pub fn store(&self, year: Year, day: Day) -> Result<Vec<PathBuf>, io::Error> {
+        let dir = get_data_dir(year).join("examples");
+        fs::create_dir_all(&dir)?;
+
+        let mut written = vec![];
+
+        for (i, input) in self.inputs.iter().enumerate() {
+            let path = if i == 0 {
+                dir.join(format!("{day}.txt"))
+            } else {
+                dir.join(format!("{day}-{}.txt", i + 1))
+            };
+
+            if fs::read_to_string(&path).is_ok_and(|contents| !contents.trim().is_empty()) {
+                continue;
+            }
+
+            fs::write(&path, input)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+/// The prose between two code blocks.
+struct Prose {
+    /// `0` for part one, `1` for part two.
+    part: usize,
+    /// The example input that precedes the prose, as an index into the inputs.
+    after_input: Option<usize>,
+    text: String,
+}
+
+/// Finds the code blocks that are introduced as examples and the prose around them.
+/// Falls back to the first block with more than one line if no block is.
+fn scan(markdown: &str) -> (Vec<String>, Vec<Prose>) {
+    // whether each block is introduced as an example, its line count and its content.
+    let mut candidates: Vec<(bool, usize, String)> = vec![];
+    // the part of each stretch of prose, how many candidates precede it and its lines.
+    let mut prose: Vec<(usize, usize, Vec<&str>)> = vec![(0, 0, vec![])];
+    let mut block: Option<Vec<&str>> = None;
+    let mut context = "";
+
+    for line in markdown.lines() {
+        match (&mut block, line.trim_end() == "```") {
+            (None, true) => block = Some(vec![]),
+            (None, false) => {
+                if line.starts_with("## --- Part Two") {
+                    prose.push((1, candidates.len(), vec![]));
+                }
+                if !line.trim().is_empty() {
+                    context = line;
+                }
+                if let Some((_, _, lines)) = prose.last_mut() {
+                    lines.push(line);
+                }
+            }
+            (Some(lines), true) => {
+                if !lines.is_empty() {
+                    let introduced = context.to_lowercase().contains("example");
+                    candidates.push((introduced, lines.len(), lines.join("\n") + "\n"));
+                }
+                block = None;
+                let part = prose.last().map_or(0, |(part, _, _)| *part);
+                prose.push((part, candidates.len(), vec![]));
+            }
+            (Some(lines), false) => lines.push(line),
+        }
+    }
+
+    let mut inputs: Vec<String> = vec![];
+    // the input each candidate was stored as, if any.
+    let mut stored_as: Vec<Option<usize>> = vec![None; candidates.len()];
+
+    for (i, (introduced, _, input)) in candidates.iter().enumerate() {
+        if !introduced {
+            continue;
+        }
+        stored_as[i] = Some(match inputs.iter().position(|known| known == input) {
+            Some(index) => index,
+            None => {
+                inputs.push(input.clone());
+                inputs.len() - 1
+            }
+        });
+    }
+
+    // a single line without an introduction is more likely an aside than an input.
+    if inputs.is_empty() {
+        if let Some(i) = candidates.iter().position(|(_, lines, _)| *lines > 1) {
+            inputs.push(candidates[i].2.clone());
+            stored_as[i] = Some(0);
+        }
+    }
+
+    let prose = prose
+        .into_iter()
+        .map(|(part, preceding, lines)| Prose {
+            part,
+            after_input: stored_as[..preceding].iter().rev().find_map(|input| *input),
+            text: lines.join("\n"),
+        })
+        .collect();
+
+    (inputs, prose)
+}
+
+/// Finds the last value that is both code and emphasized, e.g. `` `*11*` `` or `` *`11`* ``.
+fn last_emphasized_code(text: &str) -> Option<String> {
+    let segments: Vec<&str> = text.split('`').collect();
+
+    // every other segment is the content of a code span.
+    segments
+        .iter()
+        .enumerate()
+        .skip(1)
+        .step_by(2)
+        .filter_map(|(i, code)| {
+            if let Some(value) = code.strip_prefix('*').and_then(|c| c.strip_suffix('*')) {
+                return Some(value);
+            }
+
+            let is_emphasized = segments[i - 1].ends_with('*')
+                && segments.get(i + 1).is_some_and(|s| s.starts_with('*'));
+            is_emphasized.then_some(*code)
+        })
+        .rfind(|value| !value.is_empty() && !value.contains(['*', '\n']))
+        .map(str::to_string)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Examples;
+
+    const PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+Throughout the Chief's office, the historically significant locations are listed.
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+Here is a single line:
+
+```
+not an example
+```
+
+In the example above, the distances are `*2*`, `1` and `*3*`, the total distance is `*11*`!
+
+## --- Part Two ---
+
+This time, you'll need to figure out how often each number appears.
+
+Here are the same example lists again:
+
+```
+3   4
+4   3
+2   5
+```
+
+So, for these example lists, the similarity score at the end of this process is *`31`*.
+
+Once again consider this larger example:
+
+```
+1   2
+2   1
+```
+
+For this larger example, the similarity score is `*62*`.";
+
+    #[test]
+    fn finds_example_inputs() {
+        let examples = Examples::parse(PUZZLE);
+        assert_eq!(
+            examples.inputs,
+            vec![
+                "3   4\n4   3\n2   5\n".to_string(),
+                "1   2\n2   1\n".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_answers_per_part() {
+        let examples = Examples::parse(PUZZLE);
+        assert_eq!(examples.answers, [Some("11".into()), Some("62".into())]);
+        assert_eq!(examples.answer_inputs, [Some(0), Some(1)]);
+        assert_eq!(examples.template_answer(1), Some(11));
+        assert_eq!(examples.template_answer(3), None);
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let examples = Examples::parse("Consider:\n\n```\na\nb\n```\n\nThe answer is `*abc*`.");
+        assert_eq!(examples.inputs, vec!["a\nb\n".to_string()]);
+        assert_eq!(examples.answers, [Some("abc".into()), None]);
+        assert_eq!(examples.answer_inputs, [Some(0), None]);
+        assert_eq!(examples.template_answer(1), None);
+    }
+
+    #[test]
+    fn finds_one_line_examples() {
+        // based on the description of 2024 day 11.
+        let puzzle = "## --- Day 11: Plutonian Pebbles ---
+
+If you have an arrangement of five stones engraved with the numbers `0 1 10 99 999` and you blink once, the stones transform as follows.
+
+```
+0 1 10 99 999
+```
+
+Here is a longer example:
+
+```
+125 17
+```
+
+In this example, after blinking six times, you would have `22` stones. After blinking a total of 25 times, you would have *`55312`* stones!";
+
+        let examples = Examples::parse(puzzle);
+        assert_eq!(examples.inputs, vec!["125 17\n".to_string()]);
+        assert_eq!(examples.answers, [Some("55312".into()), None]);
+        assert_eq!(examples.answer_inputs, [Some(0), None]);
+    }
+
+    #[test]
+    fn skips_one_line_blocks_in_fallback() {
+        let examples =
+            Examples::parse("Consider:\n\n```\nx\n```\n\nOr:\n\n```\na\nb\n```\n\nIt is `*2*`.");
+        assert_eq!(examples.inputs, vec!["a\nb\n".to_string()]);
+        assert_eq!(examples.answer_inputs, [Some(0), None]);
+    }
+
+    #[test]
+    fn handles_puzzles_without_examples() {
+        assert_eq!(Examples::parse("Nothing here."), Examples::default());
+    }
+}
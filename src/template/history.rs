@@ -0,0 +1,468 @@
+/// An append-only log of `cargo time` runs, used to compare timings across runs.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, OpenOptions},
+    io::{Error, ErrorKind, Write},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::report::{part_label, PARSE_PART};
+use crate::template::timings::Timings;
+use crate::template::{get_data_dir, Day, Year, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+static HISTORY_FILE_NAME: &str = "timings-history.jsonl";
+
+/// Relative changes smaller than this are considered noise and not highlighted.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+fn get_history_path(year: Year) -> PathBuf {
+    get_data_dir(year).join(HISTORY_FILE_NAME)
+}
+
+/// The timings of a single `cargo time` run and the environment they were taken in.
+#[derive(Clone, Debug)]
+pub struct TimingRun {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The checked out git commit, if available.
+    pub commit: Option<String>,
+    pub rustc: Option<String>,
+    pub profile: String,
+    pub timings: Timings,
+}
+
+impl TimingRun {
+    /// Tags timings with the current time, git commit and rustc version.
+    pub fn new(timings: Timings, is_release: bool) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            timestamp,
+            commit: command_output("git", &["rev-parse", "HEAD"]),
+            rustc: command_output("rustc", &["--version"]),
+            profile: if is_release { "release" } else { "dev" }.into(),
+            timings,
+        }
+    }
+
+    /// Whether any part of the run was benched. Runs without benched parts are not worth keeping.
+    pub fn has_benched_parts(&self) -> bool {
+        !part_medians(&self.timings).is_empty()
+    }
+}
+
+/// Runs a command and returns its trimmed stdout, or `None` if it failed.
+fn command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(cmd).args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    Some(stdout.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// The run that new timings are compared against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Baseline {
+    /// The fastest median of every part across all runs.
+    Best,
+    /// The most recent run that benched a part, for every part.
+    Last,
+    /// The most recent run of a git commit, branch or tag.
+    Ref(String),
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("expecting `best`, `last` or a git ref.".into()),
+            "best" => Ok(Baseline::Best),
+            "last" => Ok(Baseline::Last),
+            s => Ok(Baseline::Ref(s.into())),
+        }
+    }
+}
+
+/// The median execution time of every part, keyed by day and part.
+pub type PartMedians = BTreeMap<(Day, u8), Duration>;
+
+/// Collects the medians of all parts and parse stages that were benched with statistics.
+pub fn part_medians(timings: &Timings) -> PartMedians {
+    timings
+        .data
+        .iter()
+        .flat_map(|t| [PARSE_PART, 1, 2].map(|part| (t.day, part, t.median(part))))
+        .filter_map(|(day, part, median)| Some(((day, part), median?)))
+        .collect()
+}
+
+/// All runs of a year, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct TimingHistory {
+    pub runs: Vec<TimingRun>,
+}
+
+impl TimingHistory {
+    /// Reads the history of a year. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Self {
+        let Ok(content) = fs::read_to_string(get_history_path(year)) else {
+            return Self::default();
+        };
+
+        // skip lines that cannot be read instead of discarding the whole history.
+        let runs = content
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .filter_map(|json| TimingRun::try_from(&json).ok())
+            .collect();
+
+        Self { runs }
+    }
+
+    /// Appends a run to the history file of a year.
+    pub fn append(year: Year, run: &TimingRun) -> Result<(), Error> {
+        let line = JsonValue::from(run)
+            .stringify()
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))?;
+
+        fs::create_dir_all(get_data_dir(year))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_history_path(year))?;
+
+        writeln!(file, "{line}")
+    }
+
+    /// Collects the medians to compare against, considering only runs of the same build profile.
+    /// A git ref is resolved to a commit before looking it up.
+    pub fn baseline(&self, baseline: &Baseline, profile: &str) -> Option<PartMedians> {
+        let runs = self.runs.iter().filter(|run| run.profile == profile);
+
+        match baseline {
+            Baseline::Best => {
+                let mut best = PartMedians::new();
+
+                for run in runs {
+                    for (key, median) in part_medians(&run.timings) {
+                        best.entry(key)
+                            .and_modify(|x| *x = (*x).min(median))
+                            .or_insert(median);
+                    }
+                }
+
+                Some(best).filter(|x| !x.is_empty())
+            }
+            Baseline::Last => {
+                let mut last = PartMedians::new();
+
+                // a run may only cover some days, so older runs fill in the parts it did not bench.
+                for run in runs.rev() {
+                    for (key, median) in part_medians(&run.timings) {
+                        last.entry(key).or_insert(median);
+                    }
+                }
+
+                Some(last).filter(|x| !x.is_empty())
+            }
+            Baseline::Ref(reference) => {
+                let commit = command_output("git", &["rev-parse", reference])
+                    .unwrap_or_else(|| reference.clone());
+
+                runs.rev()
+                    .find(|run| run.commit.as_ref().is_some_and(|c| c.starts_with(&commit)))
+                    .map(|run| part_medians(&run.timings))
+            }
+        }
+    }
+}
+
+/// The change of a part's median between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartDelta {
+    pub day: Day,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl PartDelta {
+    /// Relative change of the median, negative values are speedups.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn change(&self) -> f64 {
+        let before = self.before.as_nanos().max(1) as f64;
+        (self.after.as_nanos() as f64 - before) / before
+    }
+}
+
+/// Pairs the parts of new timings with their baseline.
+pub fn compare(baseline: &PartMedians, timings: &Timings) -> Vec<PartDelta> {
+    part_medians(timings)
+        .into_iter()
+        .filter_map(|((day, part), after)| {
+            Some(PartDelta {
+                day,
+                part,
+                before: *baseline.get(&(day, part))?,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// Prints per-part speedups and slowdowns. Changes outside the noise threshold are highlighted.
+pub fn print_comparison(baseline: &Baseline, deltas: &[PartDelta]) {
+    let name = match baseline {
+        Baseline::Best => "best run".to_string(),
+        Baseline::Last => "last run".to_string(),
+        Baseline::Ref(reference) => format!("`{reference}`"),
+    };
+
+    println!("\n{ANSI_BOLD}Compared to {name}{ANSI_RESET}");
+    println!("------");
+
+    if deltas.is_empty() {
+        println!("No timings to compare.");
+        return;
+    }
+
+    for delta in deltas {
+        let change = delta.change();
+        let change_str = format!("{:+.1}%", change * 100.0);
+
+        let highlighted = if change <= -NOISE_THRESHOLD {
+            format!("{ANSI_GREEN}{change_str}{ANSI_RESET}")
+        } else if change >= NOISE_THRESHOLD {
+            format!("{ANSI_RED}{change_str}{ANSI_RESET}")
+        } else {
+            change_str
+        };
+
+        println!(
+            "Day {} {}: {:.1?} → {:.1?} ({highlighted})",
+            delta.day,
+            part_label(delta.part),
+            delta.before,
+            delta.after
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&TimingRun> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &TimingRun) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let optional = |x: &Option<String>| x.clone().map_or(JsonValue::Null, JsonValue::String);
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("commit".into(), optional(&value.commit));
+        map.insert("rustc".into(), optional(&value.rustc));
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for TimingRun {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        let optional = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected run.{key} to be null or string."))
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected run.timestamp to be a number.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected run.profile to be a string.")?;
+
+        let timings = json
+            .get("timings")
+            .ok_or("Expected run.timings to be present.")?;
+
+        Ok(TimingRun {
+            timestamp: timestamp as u64,
+            commit: optional("commit")?.cloned(),
+            rustc: optional("rustc")?.cloned(),
+            profile: profile.clone(),
+            timings: Timings::try_from(timings)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{compare, Baseline, PartMedians, TimingHistory, TimingRun};
+    use crate::day;
+    use crate::template::runner::BenchStats;
+    use crate::template::timings::{Timing, Timings};
+
+    fn stats(micros: u64) -> Option<BenchStats> {
+        Some(BenchStats::from_samples(&[Duration::from_micros(micros)]))
+    }
+
+    fn get_mock_run(commit: &str, profile: &str, part_1: u64, part_2: u64) -> TimingRun {
+        TimingRun {
+            timestamp: 1_733_000_000,
+            commit: Some(commit.into()),
+            rustc: Some("rustc 1.83.0".into()),
+            profile: profile.into(),
+            timings: Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(format!("{part_1}µs")),
+                    part_2: Some(format!("{part_2}µs")),
+                    part_1_stats: stats(part_1),
+                    part_2_stats: stats(part_2),
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
+                    total_nanos: ((part_1 + part_2) * 1000) as f64,
+                }],
+            },
+        }
+    }
+
+    fn get_mock_history() -> TimingHistory {
+        TimingHistory {
+            runs: vec![
+                get_mock_run("aaaaaaa", "release", 10, 50),
+                get_mock_run("bbbbbbb", "release", 20, 40),
+                get_mock_run("ccccccc", "dev", 5, 5),
+                get_mock_run("ddddddd", "release", 30, 60),
+            ],
+        }
+    }
+
+    fn medians(part_1: u64, part_2: u64) -> PartMedians {
+        PartMedians::from([
+            ((day!(1), 1), Duration::from_micros(part_1)),
+            ((day!(1), 2), Duration::from_micros(part_2)),
+        ])
+    }
+
+    #[test]
+    fn roundtrips_runs() {
+        let run = get_mock_run("aaaaaaa", "release", 10, 50);
+        let json = JsonValue::from(&run);
+        let parsed = TimingRun::try_from(&json).unwrap();
+
+        assert_eq!(parsed.commit.as_deref(), Some("aaaaaaa"));
+        assert_eq!(parsed.rustc.as_deref(), Some("rustc 1.83.0"));
+        assert_eq!(parsed.profile, "release");
+        assert_eq!(parsed.timestamp, 1_733_000_000);
+        assert_eq!(
+            parsed.timings.data[0].median(2),
+            Some(Duration::from_micros(50))
+        );
+    }
+
+    #[test]
+    fn finds_baselines() {
+        let history = get_mock_history();
+
+        assert_eq!(
+            history.baseline(&Baseline::Best, "release"),
+            Some(medians(10, 40))
+        );
+        assert_eq!(
+            history.baseline(&Baseline::Last, "release"),
+            Some(medians(30, 60))
+        );
+        assert_eq!(
+            history.baseline(&Baseline::Ref("bbbbbbb".into()), "release"),
+            Some(medians(20, 40))
+        );
+        assert_eq!(
+            history.baseline(&Baseline::Ref("ccccccc".into()), "release"),
+            None
+        );
+        assert_eq!(history.baseline(&Baseline::Best, "test"), None);
+    }
+
+    #[test]
+    fn finds_last_baseline_per_part() {
+        let mut history = get_mock_history();
+
+        let mut other_day = get_mock_run("eeeeeee", "release", 70, 80);
+        other_day.timings.data[0].day = day!(2);
+        history.runs.push(other_day);
+
+        let mut medians = medians(30, 60);
+        medians.insert((day!(2), 1), Duration::from_micros(70));
+        medians.insert((day!(2), 2), Duration::from_micros(80));
+
+        assert_eq!(history.baseline(&Baseline::Last, "release"), Some(medians));
+    }
+
+    #[test]
+    fn detects_runs_without_benched_parts() {
+        let mut run = get_mock_run("aaaaaaa", "release", 10, 50);
+        assert!(run.has_benched_parts());
+
+        run.timings.data[0].part_1_stats = None;
+        run.timings.data[0].part_2_stats = None;
+        assert!(!run.has_benched_parts());
+
+        run.timings.data.clear();
+        assert!(!run.has_benched_parts());
+    }
+
+    #[test]
+    fn compares_timings() {
+        let current = get_mock_run("eeeeeee", "release", 15, 40).timings;
+        let deltas = compare(&medians(30, 40), &current);
+
+        assert_eq!(deltas.len(), 2);
+        assert!((deltas[0].change() + 0.5).abs() < 1e-9);
+        assert!(deltas[1].change().abs() < 1e-9);
+
+        let deltas = compare(&PartMedians::new(), &current);
+        assert!(deltas.is_empty());
+    }
+
+    #[test]
+    fn parses_baselines() {
+        assert_eq!("best".parse::<Baseline>(), Ok(Baseline::Best));
+        assert_eq!("last".parse::<Baseline>(), Ok(Baseline::Last));
+        assert_eq!("main".parse::<Baseline>(), Ok(Baseline::Ref("main".into())));
+    }
+}
@@ -0,0 +1,199 @@
+/// Selects the input that a solution binary runs on.
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::template::params::Params;
+use crate::template::{get_data_dir, read_file, read_params_at, Day, Year};
+
+/// Where the input of a solution binary comes from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/<year>/inputs`.
+    #[default]
+    Real,
+    /// An example in `data/<year>/examples`, optionally a numbered one like `01-2.txt`.
+    Example(Option<u8>),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads `--input <path>`, `--input -` or `--example [N]` from the arguments of the current process.
+    /// Exits if the arguments are invalid.
+    pub fn from_env() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        Self::from_args(&args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        })
+    }
+
+    /// Reads `--input <path>`, `--input -` or `--example [N]` from a list of arguments.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let input = args.iter().position(|x| x == "--input");
+        let example = args.iter().position(|x| x == "--example");
+
+        match (input, example) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` cannot be combined.".into()),
+            (Some(index), None) => match args.get(index + 1).map(String::as_str) {
+                Some("-") => Ok(InputSource::Stdin),
+                Some(path) if !path.starts_with("--") => Ok(InputSource::File(path.into())),
+                _ => Err("`--input` expects a path or `-` for stdin.".into()),
+            },
+            (None, Some(index)) => {
+                let number = args.get(index + 1).and_then(|x| x.parse::<u8>().ok());
+                Ok(InputSource::Example(number))
+            }
+            (None, None) => Ok(InputSource::Real),
+        }
+    }
+
+    /// Converts the source to flags that can be passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Real => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(number)) => vec!["--example".into(), number.to_string()],
+            InputSource::File(path) => vec!["--input".into(), path.to_string_lossy().into()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    /// Answers are only worth submitting for the real input.
+    pub fn is_real(&self) -> bool {
+        *self == InputSource::Real
+    }
+
+    /// Reads the input. Panics if it cannot be read, like [`read_file`].
+    pub fn read(&self, year: Year, day: Day) -> String {
+        match self {
+            InputSource::Real => read_file("inputs", year, day),
+            InputSource::Example(_) | InputSource::File(_) => {
+                let path = self.path(year, day).unwrap();
+                fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("could not open input file {}: {e}", path.display()))
+            }
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .expect("could not read input from stdin");
+                input
+            }
+        }
+    }
+
+    /// Reads the parameters declared for the input in its sidecar file, e.g. `input.params` for `input.txt`.
+    /// Numbered examples fall back to the parameters of the first example.
+    /// Errors name the sidecar file that could not be read.
+    pub fn read_params<P: Params>(&self, year: Year, day: Day) -> Result<P, String> {
+        let sidecar = |path: &Path| read_params_at::<P>(&path.with_extension("params"));
+
+        let params = match (self, self.path(year, day)) {
+            (InputSource::Example(Some(_)), Some(path)) => match sidecar(&path)? {
+                Some(params) => Some(params),
+                None => InputSource::Example(None)
+                    .path(year, day)
+                    .map(|path| sidecar(&path))
+                    .transpose()?
+                    .flatten(),
+            },
+            (_, Some(path)) => sidecar(&path)?,
+            (_, None) => None,
+        };
+
+        Ok(params.unwrap_or_default())
+    }
+
+    fn path(&self, year: Year, day: Day) -> Option<PathBuf> {
+        let data_dir = get_data_dir(year);
+
+        match self {
+            InputSource::Real => Some(data_dir.join("inputs").join(format!("{day}.txt"))),
+            InputSource::Example(None) => {
+                Some(data_dir.join("examples").join(format!("{day}.txt")))
+            }
+            InputSource::Example(Some(number)) => Some(
+                data_dir
+                    .join("examples")
+                    .join(format!("{day}-{number}.txt")),
+            ),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+}
+
+/// The additional inputs of a day in `data/<year>/inputs/<day>/`, e.g. inputs of other accounts.
+/// Returns the name and path of each `.txt` file, sorted by name.
+pub fn additional_inputs(year: Year, day: Day) -> Vec<(String, PathBuf)> {
+    let dir = get_data_dir(year).join("inputs").join(day.to_string());
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut inputs: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some((path.file_stem()?.to_string_lossy().into_owned(), path))
+        })
+        .collect();
+
+    inputs.sort();
+    inputs
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        ["2024-01"]
+            .iter()
+            .chain(args)
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn reads_input_sources() {
+        let cases = [
+            (vec![], InputSource::Real),
+            (vec!["--time"], InputSource::Real),
+            (vec!["--input", "-"], InputSource::Stdin),
+            (
+                vec!["--input", "in.txt"],
+                InputSource::File("in.txt".into()),
+            ),
+            (vec!["--example"], InputSource::Example(None)),
+            (vec!["--example", "--time"], InputSource::Example(None)),
+            (vec!["--example", "2"], InputSource::Example(Some(2))),
+        ];
+
+        for (flags, source) in cases {
+            assert_eq!(InputSource::from_args(&args(&flags)), Ok(source.clone()));
+
+            let to_args = source.to_args();
+            let to_args: Vec<&str> = to_args.iter().map(String::as_str).collect();
+            assert_eq!(InputSource::from_args(&args(&to_args)), Ok(source));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert!(InputSource::from_args(&args(&["--input"])).is_err());
+        assert!(InputSource::from_args(&args(&["--input", "--time"])).is_err());
+        assert!(InputSource::from_args(&args(&["--input", "-", "--example"])).is_err());
+    }
+}
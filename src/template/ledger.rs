@@ -0,0 +1,459 @@
+/// Keeps a record of every submitted answer and the verdict the server gave for it.
+/// The runner consults it before submitting so that known-wrong answers are never sent twice.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+static LEDGER_FILE_PATH: &str = "./data/submissions.json";
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+}
+
+impl Verdict {
+    /// Reads the verdict from the text of a submission response.
+    /// Returns `None` for responses that do not judge the answer, e.g. for already completed parts.
+    pub fn parse(response: &str) -> Option<Self> {
+        let response = response.to_lowercase();
+
+        if response.contains("that's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if response.contains("that's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if response.contains("you gave an answer too recently") {
+            Some(Verdict::RateLimited)
+        } else {
+            None
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate-limited",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate-limited" => Ok(Verdict::RateLimited),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub verdict: Verdict,
+}
+
+/// Why an answer was not submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    AboveBound(String),
+    BelowBound(String),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved(answer) => {
+                write!(f, "this part is already solved with answer `{answer}`.")
+            }
+            Rejection::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted and is {verdict}.")
+            }
+            Rejection::AboveBound(bound) => {
+                write!(
+                    f,
+                    "this answer is not below `{bound}`, which is known to be too high."
+                )
+            }
+            Rejection::BelowBound(bound) => {
+                write!(
+                    f,
+                    "this answer is not above `{bound}`, which is known to be too low."
+                )
+            }
+        }
+    }
+}
+
+/// All submissions made so far.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Dehydrate the ledger to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(LEDGER_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the ledger from a JSON file. If not present, returns an empty ledger.
+    /// A file that cannot be read is an error, so that it is never overwritten with a ledger that lacks its history.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(LEDGER_FILE_PATH) {
+            Ok(contents) => Ledger::try_from(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Appends a submission, stamped with the current time.
+    pub fn record(&mut self, year: Year, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        self.submissions.push(Submission {
+            year,
+            day,
+            part,
+            answer: answer.into(),
+            timestamp,
+            verdict,
+        });
+    }
+
+    /// Returns the accepted answer for a part, if there is one.
+    pub fn solution(&self, year: Year, day: Day, part: u8) -> Option<&str> {
+        self.submissions_for(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Checks whether an answer is worth submitting, given what is known about the part.
+    pub fn check(&self, year: Year, day: Day, part: u8, answer: &str) -> Result<(), Rejection> {
+        if let Some(solution) = self.solution(year, day, part) {
+            return Err(Rejection::AlreadySolved(solution.into()));
+        }
+
+        if let Some(previous) = self
+            .submissions_for(year, day, part)
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(Rejection::KnownWrong(previous.verdict));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            self.submissions_for(year, day, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, s.answer.as_str())))
+        };
+
+        if let Some((_, high)) = bound(Verdict::TooHigh)
+            .filter(|(high, _)| value >= *high)
+            .min()
+        {
+            return Err(Rejection::AboveBound(high.into()));
+        }
+
+        if let Some((_, low)) = bound(Verdict::TooLow)
+            .filter(|(low, _)| value <= *low)
+            .max()
+        {
+            return Err(Rejection::BelowBound(low.into()));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether an answer is known to be wrong, either by the accepted answer or by earlier verdicts.
+    pub fn is_wrong(&self, year: Year, day: Day, part: u8, answer: &str) -> bool {
+        match self.check(year, day, part, answer) {
+            Ok(()) => false,
+            Err(Rejection::AlreadySolved(solution)) => solution != answer,
+            Err(_) => true,
+        }
+    }
+
+    fn submissions_for(&self, year: Year, day: Day, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Ledger> for JsonValue {
+    fn from(value: Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "submissions".into(),
+            JsonValue::Array(value.submissions.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Ledger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("submissions")
+            .ok_or("expected JSON document to have key `submissions`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.submissions` to be an array.")?;
+
+        Ok(Ledger {
+            submissions: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(value.year.into_inner().into()),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let year =
+            Year::new(number("year")? as u16).ok_or("Expected submission.year to be a Year.")?;
+
+        Ok(Submission {
+            year,
+            day,
+            part: number("part")? as u8,
+            answer: answer.clone(),
+            timestamp: number("timestamp")? as u64,
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Ledger, Rejection, Verdict};
+    use crate::{day, year};
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(year!(2024), day!(1), 1, "300", Verdict::TooHigh);
+        ledger.record(year!(2024), day!(1), 1, "100", Verdict::TooLow);
+        ledger.record(year!(2024), day!(1), 1, "500", Verdict::TooHigh);
+        ledger.record(year!(2024), day!(1), 1, "50", Verdict::TooLow);
+        ledger.record(year!(2024), day!(1), 1, "150", Verdict::Wrong);
+        ledger.record(year!(2024), day!(1), 1, "160", Verdict::RateLimited);
+        ledger.record(year!(2024), day!(2), 1, "7", Verdict::Correct);
+        ledger
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently; you have to wait."),
+            Some(Verdict::RateLimited)
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            None
+        );
+    }
+
+    #[test]
+    fn accepts_unknown_answers_within_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(ledger.check(year!(2024), day!(1), 1, "200"), Ok(()));
+        assert_eq!(ledger.check(year!(2024), day!(1), 1, "160"), Ok(()));
+        assert_eq!(ledger.check(year!(2024), day!(1), 2, "150"), Ok(()));
+        assert_eq!(ledger.check(year!(2023), day!(2), 1, "8"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(year!(2024), day!(1), 1, "150"),
+            Err(Rejection::KnownWrong(Verdict::Wrong))
+        );
+        assert_eq!(
+            ledger.check(year!(2024), day!(1), 1, "300"),
+            Err(Rejection::KnownWrong(Verdict::TooHigh))
+        );
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(year!(2024), day!(1), 1, "400"),
+            Err(Rejection::AboveBound("300".into()))
+        );
+        assert_eq!(
+            ledger.check(year!(2024), day!(1), 1, "70"),
+            Err(Rejection::BelowBound("100".into()))
+        );
+    }
+
+    #[test]
+    fn rejects_solved_parts() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(year!(2024), day!(2), 1, "8"),
+            Err(Rejection::AlreadySolved("7".into()))
+        );
+    }
+
+    #[test]
+    fn detects_wrong_answers() {
+        let ledger = get_mock_ledger();
+        assert!(!ledger.is_wrong(year!(2024), day!(2), 1, "7"));
+        assert!(ledger.is_wrong(year!(2024), day!(2), 1, "8"));
+        assert!(ledger.is_wrong(year!(2024), day!(1), 1, "150"));
+        assert!(ledger.is_wrong(year!(2024), day!(1), 1, "400"));
+        assert!(!ledger.is_wrong(year!(2024), day!(1), 1, "200"));
+        assert!(!ledger.is_wrong(year!(2024), day!(3), 1, "1"));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let ledger = get_mock_ledger();
+        let json = tinyjson::JsonValue::from(ledger.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Ledger::try_from(json).unwrap();
+        assert_eq!(parsed.submissions.len(), ledger.submissions.len());
+        assert_eq!(parsed.submissions[0].answer, "300");
+        assert_eq!(parsed.submissions[0].verdict, Verdict::TooHigh);
+        assert_eq!(parsed.submissions[6].year, year!(2024));
+        assert_eq!(
+            parsed.submissions[6].timestamp,
+            ledger.submissions[6].timestamp
+        );
+    }
+}
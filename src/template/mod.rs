@@ -1,59 +1,207 @@
-use std::{env, fs};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod history;
+pub mod input;
+pub mod params;
+pub mod perf_check;
+pub mod registry;
+pub mod report;
 pub mod runner;
 
 pub use day::*;
+pub use year::*;
 
+// the heap profiler of `dhat-heap` brings its own global allocator.
+#[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+mod allocations;
+mod answers;
 mod day;
+mod examples;
+mod ledger;
 mod readme_benchmarks;
+mod resources;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_RED: &str = "\x1b[31m";
+
+/// Returns the data directory of a year, e.g. `data/2024`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> PathBuf {
+    PathBuf::from("data").join(year.to_string())
+}
+
+/// Returns the name of the solution binary for a day, e.g. `2024-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(get_data_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the parameters declared for an input in its sidecar file, e.g. `14.params` for `14.txt`.
+/// Falls back to the defaults, i.e. the values for the real input, if there is no sidecar file.
+#[must_use]
+pub fn read_params<P: params::Params>(folder: &str, year: Year, day: Day) -> P {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(get_data_dir(year))
+        .join(folder)
+        .join(format!("{day}.params"));
+
+    read_params_at(&filepath)
+        .unwrap_or_else(|e| panic!("{e}"))
+        .unwrap_or_default()
+}
+
+/// Reads a parameters file, if it exists. Errors name the file.
+fn read_params_at<P: params::Params>(path: &Path) -> Result<Option<P>, String> {
+    let error = |e: String| format!("could not read params \"{}\": {e}", path.display());
+
+    match fs::read_to_string(path) {
+        Ok(text) => params::parse(&text).map(Some).map_err(error),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(error(e.to_string())),
+    }
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// Also defines a `SOLUTION` that lets the `registry` binary run the day in-process.
+/// Parts return an `Option<T>` or, if they can fail, a `Result<T, E>` whose error is printed,
+/// see [`PartOutput`](crate::template::runner::PartOutput).
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Options can be appended in any order:
+///  - `1` or `2` only runs a single part of the solution.
+///  - `parse` runs a `parse` function once and hands its output to both parts by reference,
+///    e.g. for `fn parse(input: &str) -> T` and `fn part_one(input: &T)`.
+///    The parse stage is timed and reported separately from the parts. `parse` can also return a
+///    `Result<T, E>`: if it fails, the error is printed and the parts are skipped. A
+///    [`ParseError`](crate::parse::ParseError) is printed with the offending line.
+///  - `params` passes the parameters of the input as second argument to `parse` and the parts,
+///    e.g. `fn part_one(input: &str, params: &Params)`. See [`params!`](crate::params).
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr $(, $option:tt)*) => {
+        $crate::solution!(@options $year, $day, [] [] [[part_one, 1] [part_two, 2]] $($option)*);
+    };
+
+    (@options $year:expr, $day:expr, [] $params:tt $parts:tt parse $($rest:tt)*) => {
+        $crate::solution!(@options $year, $day, [parse] $params $parts $($rest)*);
+    };
+    (@options $year:expr, $day:expr, $parse:tt [] $parts:tt params $($rest:tt)*) => {
+        $crate::solution!(@options $year, $day, $parse [params] $parts $($rest)*);
+    };
+    (@options $year:expr, $day:expr, $parse:tt $params:tt $parts:tt 1 $($rest:tt)*) => {
+        $crate::solution!(@options $year, $day, $parse $params [[part_one, 1]] $($rest)*);
+    };
+    (@options $year:expr, $day:expr, $parse:tt $params:tt $parts:tt 2 $($rest:tt)*) => {
+        $crate::solution!(@options $year, $day, $parse $params [[part_two, 2]] $($rest)*);
+    };
+    (@options $year:expr, $day:expr, $parse:tt $params:tt $parts:tt) => {
+        $crate::solution!(@impl $year, $day, $parse $params $parts);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+
+    // reads the parameters of the input, if the day has any.
+    (@read_params [], $source:expr, |$error:ident| $on_error:expr) => {};
+    (@read_params [$params:ident], $source:expr, |$error:ident| $on_error:expr) => {
+        let $params = match $source.read_params(YEAR, DAY) {
+            Ok(params) => params,
+            Err($error) => $on_error,
+        };
+    };
+
+    // turns the output of `parse` into a `Result`, whether or not `parse` can fail.
+    (@parse_result $func:expr) => {
+        |input| {
+            let output = $func(input);
+            (&output).parse_kind().into_result(output)
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+
+    // passes the parameters to a function, if the day has any.
+    (@with_params $func:expr, []) => {
+        $func
+    };
+    (@with_params $func:expr, [$params:ident]) => {
+        |input| $func(input, &$params)
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, [$($parse:ident)?] $params:tt [$( [$func:expr, $part:expr] )*]) => {
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = $crate::year!($year);
+
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        const DAY: $crate::template::Day = $crate::day!($day, YEAR);
+
+        /// The parts of the current day, as run by the `registry` binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            year: YEAR,
+            day: DAY,
+            run: |source, bench_config| {
+                use $crate::template::runner::*;
+                let input = source.read(YEAR, DAY);
+                let input = input.as_str();
+                // the registry moves on to the next day instead of exiting.
+                $crate::solution!(@read_params $params, source, |e| return vec![report_input_error(&e, DAY)]);
+                let mut reports = vec![];
+                $(
+                    let (parsed, report) = run_parse_with(
+                        $crate::solution!(@parse_result $crate::solution!(@with_params $parse, $params)),
+                        input,
+                        DAY,
+                        bench_config,
+                    );
+                    reports.push(report);
+                    let Ok(parsed) = parsed else {
+                        return reports;
+                    };
+                    let input = &parsed;
+                )?
+                $(
+                    reports.push(run_part_with(
+                        $crate::solution!(@with_params $func, $params),
+                        input,
+                        DAY,
+                        $part,
+                        bench_config,
+                    ));
+                )*
+                reports
+            },
+        };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +209,22 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let source = $crate::template::input::InputSource::from_env();
+            let input = source.read(YEAR, DAY);
+            let input = input.as_str();
+            $crate::solution!(@read_params $params, source, |e| {
+                report_input_error(&e, DAY);
+                std::process::exit(1)
+            });
+            $(
+                let parsed = run_parse(
+                    $crate::solution!(@parse_result $crate::solution!(@with_params $parse, $params)),
+                    input,
+                    DAY,
+                );
+                let input = &parsed;
+            )?
+            $( run_part($crate::solution!(@with_params $func, $params), input, YEAR, DAY, $part); )*
         }
     };
 }
@@ -0,0 +1,125 @@
+/// Parameters of a solution that differ between inputs, e.g. the size of a grid that is not part of the input.
+/// A day declares them with the [`params!`](crate::params) macro, using the values for the real input as defaults.
+/// An input overrides them in a sidecar file, e.g. `examples/14.params` next to `examples/14.txt`:
+///
+/// ```text
+/// # the example grid is smaller than the real one.
+/// width = 11
+/// height = 7
+/// ```
+pub trait Params: Default {
+    /// Sets a parameter from its textual value.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+/// Reads `key = value` lines on top of the defaults. Empty lines and lines starting with `#` are skipped.
+pub fn parse<P: Params>(text: &str) -> Result<P, String> {
+    let mut params = P::default();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(format!("expected `{line}` to be `key = value`."))?;
+
+        params.set(key.trim(), value.trim())?;
+    }
+
+    Ok(params)
+}
+
+/// Declares the parameters of a solution as a struct, with the values for the real input as defaults.
+///
+/// ```ignore
+/// advent_of_code::params! {
+///     pub struct Params {
+///         width: usize = 101,
+///         height: usize = 103,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$field_meta:meta])* $field:ident : $ty:ty = $default:expr ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        $vis struct $name {
+            $( $(#[$field_meta])* pub $field: $ty, )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $( $field: $default, )*
+                }
+            }
+        }
+
+        impl $crate::template::params::Params for $name {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value
+                                .parse()
+                                .map_err(|_| format!("expected `{value}` to be a valid `{key}`."))?;
+                        }
+                    )*
+                    _ => return Err(format!("unknown parameter `{key}`.")),
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse;
+
+    crate::params! {
+        struct Params {
+            size: usize = 71,
+            bytes: u32 = 1024,
+            label: String = "real".into(),
+        }
+    }
+
+    #[test]
+    fn uses_defaults() {
+        let params: Params = parse("").unwrap();
+        assert_eq!(params, Params::default());
+        assert_eq!(params.size, 71);
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        let params: Params = parse("# the example\nsize = 7\n\n bytes=12 ").unwrap();
+        assert_eq!(
+            params,
+            Params {
+                size: 7,
+                bytes: 12,
+                label: "real".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_params() {
+        assert!(parse::<Params>("size = -1").is_err());
+        assert!(parse::<Params>("width = 7").is_err());
+        assert!(parse::<Params>("size").is_err());
+    }
+}
@@ -0,0 +1,244 @@
+/// Compares new timings against the stored baseline to catch performance regressions.
+use std::{collections::HashSet, time::Duration};
+
+use crate::template::history::{compare, part_medians, PartDelta};
+use crate::template::report::part_label;
+use crate::template::timings::Timings;
+use crate::template::{Day, ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET};
+
+/// Limits that benched timings have to stay within.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckConfig {
+    /// How much slower than its baseline a part may get, relative to the baseline.
+    pub tolerance: f64,
+    /// The maximum total time of a single day.
+    pub budget: Option<Duration>,
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        Self {
+            tolerance: 0.1,
+            budget: None,
+        }
+    }
+}
+
+/// A timing that exceeds the limits of a [`CheckConfig`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation {
+    /// A part got slower than its baseline beyond the tolerance.
+    Regression(PartDelta),
+    /// The parts of a day took longer than the budget.
+    OverBudget { day: Day, total: Duration },
+    /// No part of a day could be compared, because the baseline has no benched parts of it.
+    NoBaseline(Day),
+    /// No day was timed, e.g. because no timings are stored that could be re-run.
+    NothingCompared,
+}
+
+/// The result of checking timings against a baseline.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CheckReport {
+    pub deltas: Vec<PartDelta>,
+    pub violations: Vec<Violation>,
+}
+
+impl CheckReport {
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Checks every part of `timings` against its baseline and every day against the budget.
+/// Parts without a baseline are only checked against the budget. Without a budget, a day that
+/// cannot be compared at all is a violation, so that a check that compares nothing never passes.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn check(baseline: &Timings, timings: &Timings, config: &CheckConfig) -> CheckReport {
+    let deltas = compare(&part_medians(baseline), timings);
+
+    let mut violations: Vec<Violation> = deltas
+        .iter()
+        .filter(|delta| delta.change() > config.tolerance)
+        .cloned()
+        .map(Violation::Regression)
+        .collect();
+
+    if timings.data.is_empty() {
+        violations.push(Violation::NothingCompared);
+    } else if config.budget.is_none() {
+        let compared: HashSet<Day> = deltas.iter().map(|delta| delta.day).collect();
+        violations.extend(
+            timings
+                .data
+                .iter()
+                .map(|t| t.day)
+                .filter(|day| !compared.contains(day))
+                .map(Violation::NoBaseline),
+        );
+    }
+
+    if let Some(budget) = config.budget {
+        violations.extend(
+            timings
+                .data
+                .iter()
+                .map(|t| (t.day, Duration::from_nanos(t.total_nanos as u64)))
+                .filter(|(_, total)| *total > budget)
+                .map(|(day, total)| Violation::OverBudget { day, total }),
+        );
+    }
+
+    CheckReport { deltas, violations }
+}
+
+/// Prints how each part compares to its baseline, followed by all violations.
+pub fn print_report(report: &CheckReport, config: &CheckConfig) {
+    println!("\n{ANSI_BOLD}Performance check{ANSI_RESET}");
+    println!("------");
+
+    for delta in &report.deltas {
+        let change = delta.change();
+        let status = if change > config.tolerance {
+            format!("{ANSI_RED}slower{ANSI_RESET}")
+        } else {
+            format!("{ANSI_GREEN}ok{ANSI_RESET}")
+        };
+
+        println!(
+            "Day {} {}: {:.1?} → {:.1?} ({:+.1}%) {status}",
+            delta.day,
+            part_label(delta.part),
+            delta.before,
+            delta.after,
+            change * 100.0
+        );
+    }
+
+    for violation in &report.violations {
+        match violation {
+            Violation::Regression(_) => {}
+            Violation::OverBudget { day, total } => println!(
+                "Day {day}: {total:.1?} {ANSI_RED}over budget{ANSI_RESET} of {:.1?}",
+                config.budget.unwrap_or_default()
+            ),
+            Violation::NoBaseline(day) => {
+                println!("Day {day}: {ANSI_RED}no baseline{ANSI_RESET} to compare against");
+            }
+            Violation::NothingCompared => {
+                println!("{ANSI_RED}No stored timings to compare against.{ANSI_RESET}");
+            }
+        }
+    }
+
+    if report.passed() {
+        println!("\nAll timings within limits.");
+    } else {
+        println!(
+            "\n{ANSI_BOLD}{} violation(s){ANSI_RESET} with a tolerance of {:.0}%.",
+            report.violations.len(),
+            config.tolerance * 100.0
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{check, CheckConfig, Violation};
+    use crate::day;
+    use crate::template::runner::BenchStats;
+    use crate::template::timings::{Timing, Timings};
+
+    fn get_mock_timings(part_1: u64, part_2: u64) -> Timings {
+        let stats = |micros: u64| Some(BenchStats::from_samples(&[Duration::from_micros(micros)]));
+
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                parse: None,
+                part_1: Some(format!("{part_1}µs")),
+                part_2: Some(format!("{part_2}µs")),
+                part_1_stats: stats(part_1),
+                part_2_stats: stats(part_2),
+                parse_stats: None,
+                parse_memory: None,
+                part_1_memory: None,
+                part_2_memory: None,
+                resources: None,
+                total_nanos: ((part_1 + part_2) * 1000) as f64,
+            }],
+        }
+    }
+
+    #[test]
+    fn passes_within_tolerance() {
+        let report = check(
+            &get_mock_timings(100, 200),
+            &get_mock_timings(109, 150),
+            &CheckConfig::default(),
+        );
+        assert!(report.passed());
+        assert_eq!(report.deltas.len(), 2);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let report = check(
+            &get_mock_timings(100, 200),
+            &get_mock_timings(120, 200),
+            &CheckConfig::default(),
+        );
+        assert!(!report.passed());
+        assert!(
+            matches!(&report.violations[..], [Violation::Regression(delta)] if delta.part == 1)
+        );
+
+        let lenient = CheckConfig {
+            tolerance: 0.5,
+            budget: None,
+        };
+        let report = check(
+            &get_mock_timings(100, 200),
+            &get_mock_timings(120, 200),
+            &lenient,
+        );
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn fails_without_baseline() {
+        // timings stored before bench stats were recorded.
+        let mut baseline = get_mock_timings(100, 200);
+        baseline.data[0].part_1_stats = None;
+        baseline.data[0].part_2_stats = None;
+
+        let config = CheckConfig::default();
+        let report = check(&baseline, &get_mock_timings(100, 200), &config);
+        assert!(report.deltas.is_empty());
+        assert_eq!(report.violations, vec![Violation::NoBaseline(day!(1))]);
+
+        let report = check(&Timings::default(), &Timings::default(), &config);
+        assert_eq!(report.violations, vec![Violation::NothingCompared]);
+    }
+
+    #[test]
+    fn detects_days_over_budget() {
+        let config = CheckConfig {
+            tolerance: 0.1,
+            budget: Some(Duration::from_micros(250)),
+        };
+        let report = check(&Timings::default(), &get_mock_timings(100, 200), &config);
+        assert!(report.deltas.is_empty());
+        assert_eq!(
+            report.violations,
+            vec![Violation::OverBudget {
+                day: day!(1),
+                total: Duration::from_micros(300)
+            }]
+        );
+    }
+}
@@ -2,8 +2,9 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::runner::format_bytes;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -26,8 +27,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,47 +53,80 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings_by_year: Vec<(Year, Timings)>) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    for (year, timings) in timings_by_year {
+        let total_millis = timings.total_millis();
+
+        // memory columns are only shown once a day was measured with `cargo time --memory`.
+        let has_memory = timings.data.iter().any(|t| t.memory().is_some());
+
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+
+        if has_memory {
+            lines.push(
+                "| Day | Parse | Part 1 | Part 2 | Allocations | Allocated | Peak memory |".into(),
+            );
+            lines.push("| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+        } else {
+            lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+            lines.push("| :---: | :---: | :---: | :---:  |".into());
+        }
+
+        for timing in timings.data {
+            let path = get_path_for_bin(year, timing.day);
+            let memory = timing.memory();
+
+            let mut line = format!(
+                "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+                timing.day.into_inner(),
+                path,
+                timing.parse.unwrap_or_else(|| "-".into()),
+                timing.part_1.unwrap_or_else(|| "-".into()),
+                timing.part_2.unwrap_or_else(|| "-".into())
+            );
+
+            if has_memory {
+                let (allocations, allocated, peak) =
+                    memory.map_or(("-".into(), "-".into(), "-".into()), |m| {
+                        (
+                            m.allocations.to_string(),
+                            format_bytes(m.total_bytes),
+                            format_bytes(m.peak_bytes),
+                        )
+                    });
+                line += &format!(" `{allocations}` | `{allocated}` | `{peak}` |");
+            }
+
+            lines.push(line);
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Total: {total_millis:.2}ms**"));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(s: &mut String, timings_by_year: Vec<(Year, Timings)>) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings_by_year);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Renders one benchmark table per year into the readme.
+pub fn update(timings_by_year: Vec<(Year, Timings)>) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, timings_by_year)?;
     fs::write(path, &readme)?;
     Ok(())
 }
@@ -100,28 +134,62 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::runner::MemoryStats,
+        template::timings::{Timing, Timings},
+        template::Year,
+        year,
+    };
 
-    fn get_mock_timings() -> Timings {
+    fn get_mock_timings() -> Vec<(Year, Timings)> {
+        vec![(year!(2024), get_mock_year())]
+    }
+
+    fn get_mock_year() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
+                    total_nanos: 3e+7,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
+                    total_nanos: 7e+7,
                 },
                 Timing {
                     day: day!(4),
+                    parse: Some("5ms".into()),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
+                    total_nanos: 9.5e+7,
                 },
             ],
         }
@@ -131,28 +199,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +228,63 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2024",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `5ms` | `40ms` | `50ms` |",
             "",
-            "**Total: 190.00ms**",
+            "**Total: 195.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_per_year() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings.insert(0, (year!(2023), get_mock_year()));
+        update_content(&mut s, timings).unwrap();
+        assert_eq!(s.matches("**Total: 195.00ms**").count(), 2);
+        assert!(s.find("### 2023").unwrap() < s.find("### 2024").unwrap());
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"));
+    }
+
+    #[test]
+    fn format_memory_columns() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut year = get_mock_year();
+        year.data[0].part_1_memory = Some(MemoryStats {
+            allocations: 3,
+            total_bytes: 2048,
+            peak_bytes: 1024,
+        });
+        year.data[0].part_2_memory = Some(MemoryStats {
+            allocations: 1,
+            total_bytes: 100,
+            peak_bytes: 100,
+        });
+        update_content(&mut s, vec![(year!(2024), year)]).unwrap();
+        assert!(
+            s.contains("| Day | Parse | Part 1 | Part 2 | Allocations | Allocated | Peak memory |")
+        );
+        assert!(s.contains(
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10ms` | `20ms` | `4` | `2.1 KiB` | `1.0 KiB` |"
+        ));
+        assert!(s.contains(
+            "| [Day 2](./src/bin/2024-02.rs) | `-` | `30ms` | `40ms` | `-` | `-` | `-` |"
+        ));
+    }
 }
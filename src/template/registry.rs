@@ -0,0 +1,104 @@
+/// Runs many solutions inside a single process.
+/// The `registry` binary includes every solution in `src/bin` as a module and collects the
+/// [`Solution`] that the `solution!` macro defines for each of them.
+use std::{env, panic, process};
+
+use crate::template::input::InputSource;
+use crate::template::report::{DayReport, DayStatus, PartReport, PartStatus};
+use crate::template::run_multi::{print_day_header, print_input};
+use crate::template::runner::BenchConfig;
+use crate::template::{Day, Year};
+
+/// The parts of a day's solution, as registered by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Runs all parts of the solution on an input, benching them if a config is passed.
+    pub run: fn(&InputSource, Option<&BenchConfig>) -> Vec<PartReport>,
+}
+
+/// Entry point of the `registry` binary.
+/// Expects `--year <year> --days <day,...>` and optionally `--time` followed by bench flags.
+/// Passing `--input <path>` runs the days on another input than the real one.
+pub fn main(solutions: &[Solution]) {
+    let args: Vec<String> = env::args().collect();
+
+    let value = |flag: &str| {
+        let index = args.iter().position(|x| x == flag)?;
+        args.get(index + 1)
+    };
+
+    let Some(year) = value("--year").and_then(|x| x.parse::<Year>().ok()) else {
+        eprintln!("Unexpected command-line input. Format: registry --year 2024 --days 1,2");
+        process::exit(1);
+    };
+
+    let Some(days) = value("--days").and_then(|x| {
+        x.split(',')
+            .map(|d| year.parse_day(d).ok())
+            .collect::<Option<Vec<Day>>>()
+    }) else {
+        eprintln!("Unexpected command-line input. Format: registry --year 2024 --days 1,2");
+        process::exit(1);
+    };
+
+    let bench_config = args
+        .iter()
+        .any(|x| x == "--time")
+        .then(|| BenchConfig::from_args(&args));
+
+    let input = InputSource::from_env();
+
+    if !run(solutions, year, &days, &input, bench_config.as_ref()) {
+        process::exit(1);
+    }
+}
+
+/// Runs a set of days back to back. A panicking day does not stop the remaining days.
+/// Emits a day record once a day has finished. Returns `false` if any day panicked.
+pub fn run(
+    solutions: &[Solution],
+    year: Year,
+    days: &[Day],
+    input: &InputSource,
+    bench_config: Option<&BenchConfig>,
+) -> bool {
+    let mut success = true;
+
+    for (i, day) in days.iter().enumerate() {
+        print_day_header(*day, i > 0);
+        print_input(input);
+
+        let status = match solutions.iter().find(|s| s.year == year && s.day == *day) {
+            Some(solution) => {
+                // the panic message is printed by the default hook, move on to the next day.
+                let result = panic::catch_unwind(|| (solution.run)(input, bench_config));
+
+                match result {
+                    Ok(parts) if parts.iter().any(|p| p.status == PartStatus::Error) => {
+                        DayStatus::Error
+                    }
+                    Ok(parts) if parts.iter().all(|p| p.status == PartStatus::Ok) => DayStatus::Ok,
+                    Ok(_) => DayStatus::Unsolved,
+                    Err(_) => {
+                        success = false;
+                        DayStatus::Panicked
+                    }
+                }
+            }
+            None => {
+                println!("Not solved.");
+                DayStatus::Unsolved
+            }
+        };
+
+        let report = DayReport { day: *day, status };
+
+        if let Err(e) = report.emit() {
+            eprintln!("failed to write report: {e}");
+        }
+    }
+
+    success
+}
@@ -0,0 +1,458 @@
+/// Machine-readable results that solution binaries hand to the process that invoked them.
+/// If `AOC_REPORT_FILE` is set, the runner appends one JSON record per part to that file.
+/// The `registry` binary also appends a record per day once the day has finished.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::runner::{BenchStats, MemoryStats};
+use crate::template::Day;
+
+pub static REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// The outcome of running a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Ok,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part returned an `Err`, or the input could not be parsed.
+    Error,
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PartStatus::Ok => "ok",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Error => "error",
+        })
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(PartStatus::Ok),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "error" => Ok(PartStatus::Error),
+            _ => Err(format!("unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The outcome of running all parts of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    Ok,
+    /// The day was not scaffolded or a part returned `None`.
+    Unsolved,
+    /// A part returned an `Err`, or the input could not be parsed.
+    Error,
+    Panicked,
+    CompileError,
+    TimedOut,
+    /// A part returned an answer that the submission ledger knows to be wrong.
+    WrongAnswer,
+}
+
+impl DayStatus {
+    pub fn is_failure(self) -> bool {
+        !matches!(self, DayStatus::Ok | DayStatus::Unsolved)
+    }
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DayStatus::Ok => "ok",
+            DayStatus::Unsolved => "unsolved",
+            DayStatus::Error => "error",
+            DayStatus::Panicked => "panicked",
+            DayStatus::CompileError => "compile error",
+            DayStatus::TimedOut => "timed out",
+            DayStatus::WrongAnswer => "wrong answer",
+        })
+    }
+}
+
+impl FromStr for DayStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ok" => Ok(DayStatus::Ok),
+            "unsolved" => Ok(DayStatus::Unsolved),
+            "error" => Ok(DayStatus::Error),
+            "panicked" => Ok(DayStatus::Panicked),
+            "compile error" => Ok(DayStatus::CompileError),
+            "timed out" => Ok(DayStatus::TimedOut),
+            "wrong answer" => Ok(DayStatus::WrongAnswer),
+            _ => Err(format!("unknown day status `{s}`.")),
+        }
+    }
+}
+
+/// The part number under which the parse stage of a solution is reported.
+pub const PARSE_PART: u8 = 0;
+
+/// Names a part for output, e.g. `Part 1` or `Parse` for the parse stage.
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// The record emitted for a single solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    /// The part number, or [`PARSE_PART`] for the parse stage.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: PartStatus,
+    /// Duration of the first execution.
+    pub duration: Duration,
+    /// Present if the part was benched.
+    pub stats: Option<BenchStats>,
+    /// Present if the heap usage of the part was measured.
+    pub memory: Option<MemoryStats>,
+}
+
+impl PartReport {
+    /// The number of times the part was executed.
+    pub fn samples(&self) -> u128 {
+        self.stats.as_ref().map_or(1, |s| s.samples)
+    }
+
+    /// Appends the record to the report file, if the invoking process asked for one.
+    pub fn emit(&self) -> io::Result<()> {
+        emit_record(JsonValue::from(self))
+    }
+}
+
+/// The record emitted once all parts of a day have run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayReport {
+    pub day: Day,
+    pub status: DayStatus,
+}
+
+impl DayReport {
+    /// Appends the record to the report file, if the invoking process asked for one.
+    pub fn emit(&self) -> io::Result<()> {
+        emit_record(JsonValue::from(self))
+    }
+}
+
+fn emit_record(json: JsonValue) -> io::Result<()> {
+    let Some(path) = env::var_os(REPORT_FILE_ENV) else {
+        return Ok(());
+    };
+
+    let line = json
+        .stringify()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// All records of a report file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Reports {
+    pub parts: Vec<PartReport>,
+    pub days: Vec<DayReport>,
+}
+
+/// Reads all records of a report file. A missing file yields no records.
+pub fn read_reports(path: &Path) -> Result<Reports, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(Reports::default());
+    };
+
+    let mut reports = Reports::default();
+
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        let json = JsonValue::from_str(line).or(Err("not a valid JSON record."))?;
+
+        // day records are the ones without a part.
+        let is_part = json
+            .get::<HashMap<String, JsonValue>>()
+            .is_some_and(|x| x.contains_key("part"));
+
+        if is_part {
+            reports.parts.push(PartReport::try_from(&json)?);
+        } else {
+            reports.days.push(DayReport::try_from(&json)?);
+        }
+    }
+
+    Ok(reports)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(value.day.into_inner().into()),
+        );
+        map.insert("part".into(), JsonValue::Number(value.part.into()));
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        map.insert("samples".into(), JsonValue::Number(value.samples() as f64));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "memory".into(),
+            value
+                .memory
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<f64>().copied())
+            .and_then(|v| Day::new(v as u8))
+            .ok_or("Expected report.day to be a valid day.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.duration_nanos to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(MemoryStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartReport {
+            day,
+            part: part as u8,
+            answer: answer.cloned(),
+            status,
+            duration: Duration::from_nanos(duration_nanos as u64),
+            stats,
+            memory,
+        })
+    }
+}
+
+impl From<&DayReport> for JsonValue {
+    fn from(value: &DayReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "day".into(),
+            JsonValue::Number(value.day.into_inner().into()),
+        );
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<f64>().copied())
+            .and_then(|v| Day::new(v as u8))
+            .ok_or("Expected report.day to be a valid day.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        Ok(DayReport { day, status })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{read_reports, DayReport, DayStatus, PartReport, PartStatus, Reports};
+    use crate::day;
+    use crate::template::runner::{BenchStats, MemoryStats};
+
+    fn get_mock_reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                day: day!(1),
+                part: 1,
+                answer: Some("Part 2: 10 (3.0ms @ 10 samples)\nsecond line".into()),
+                status: PartStatus::Ok,
+                duration: Duration::from_micros(1500),
+                stats: Some(BenchStats {
+                    min: Duration::from_micros(1200),
+                    median: Duration::from_micros(1500),
+                    mean: Duration::from_micros(1600),
+                    stddev: Duration::from_micros(120),
+                    p95: Duration::from_micros(2000),
+                    samples: 500,
+                    outliers: 3,
+                }),
+                memory: Some(MemoryStats {
+                    allocations: 12,
+                    total_bytes: 4096,
+                    peak_bytes: 1024,
+                }),
+            },
+            PartReport {
+                day: day!(1),
+                part: 2,
+                answer: None,
+                status: PartStatus::Unsolved,
+                duration: Duration::from_nanos(40),
+                stats: None,
+                memory: None,
+            },
+            PartReport {
+                day: day!(2),
+                part: 1,
+                answer: None,
+                status: PartStatus::Error,
+                duration: Duration::from_nanos(70),
+                stats: None,
+                memory: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let path = env::temp_dir().join(format!("aoc-report-test-{}.jsonl", std::process::id()));
+        let day_report = DayReport {
+            day: day!(1),
+            status: DayStatus::TimedOut,
+        };
+        let mut content = get_mock_reports()
+            .iter()
+            .map(|r| tinyjson::JsonValue::from(r).stringify().unwrap())
+            .collect::<Vec<_>>();
+        content.push(tinyjson::JsonValue::from(&day_report).stringify().unwrap());
+        fs::write(&path, content.join("\n")).unwrap();
+
+        let reports = read_reports(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(reports.parts, get_mock_reports());
+        assert_eq!(reports.days, vec![day_report]);
+        assert_eq!(reports.parts[0].samples(), 500);
+        assert_eq!(reports.parts[1].samples(), 1);
+    }
+
+    #[test]
+    fn handles_missing_report_files() {
+        let path = env::temp_dir().join("aoc-report-test-missing.jsonl");
+        assert_eq!(read_reports(&path).unwrap(), Reports::default());
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        let path =
+            env::temp_dir().join(format!("aoc-report-test-bad-{}.jsonl", std::process::id()));
+        fs::write(&path, "{ \"part\": 1 }\n").unwrap();
+        let result = read_reports(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_day_statuses() {
+        for status in [
+            DayStatus::Ok,
+            DayStatus::Unsolved,
+            DayStatus::Error,
+            DayStatus::Panicked,
+            DayStatus::CompileError,
+            DayStatus::TimedOut,
+            DayStatus::WrongAnswer,
+        ] {
+            assert_eq!(status.to_string().parse::<DayStatus>(), Ok(status));
+        }
+
+        assert!(!DayStatus::Unsolved.is_failure());
+        assert!(DayStatus::WrongAnswer.is_failure());
+        assert!(DayStatus::Error.is_failure());
+    }
+}
@@ -0,0 +1,155 @@
+/// Measures the resources a child process used, as reported by the operating system when it exits.
+/// Wall time alone hides how many cores a solution keeps busy, CPU time shows it.
+use std::{
+    fmt::Display,
+    io,
+    process::{Child, ExitStatus},
+    time::{Duration, Instant},
+};
+
+use crate::template::runner::format_bytes;
+
+/// The resources a child process used over its lifetime.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceUsage {
+    /// The peak resident set size.
+    pub peak_rss_bytes: u64,
+    pub user_time: Duration,
+    pub system_time: Duration,
+    /// The time from starting the process until it exited.
+    pub wall_time: Duration,
+}
+
+impl ResourceUsage {
+    /// The CPU time spent per wall time. Values above 1 mean the process kept more than one core busy.
+    pub fn cpu_ratio(&self) -> f64 {
+        if self.wall_time.is_zero() {
+            return 0.0;
+        }
+
+        (self.user_time + self.system_time).as_secs_f64() / self.wall_time.as_secs_f64()
+    }
+}
+
+impl Display for ResourceUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak RSS, {:.1?} user + {:.1?} system CPU in {:.1?} ({:.2}x CPU/wall)",
+            format_bytes(self.peak_rss_bytes),
+            self.user_time,
+            self.system_time,
+            self.wall_time,
+            self.cpu_ratio()
+        )
+    }
+}
+
+/// Checks if a child has exited without blocking, like [`Child::try_wait`].
+/// Also returns the resources the child used, where the operating system reports them.
+/// Once the child has exited, it must not be waited for again.
+#[cfg(unix)]
+pub fn try_wait(
+    child: &mut Child,
+    started: Instant,
+) -> io::Result<Option<(ExitStatus, Option<ResourceUsage>)>> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut status = 0;
+    // SAFETY: `rusage` is a plain C struct, for which all zeroes is a valid value.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+
+    // SAFETY: the pointers are valid for the duration of the call.
+    #[allow(clippy::cast_possible_wrap)]
+    let pid = unsafe {
+        libc::wait4(
+            child.id() as libc::pid_t,
+            &mut status,
+            libc::WNOHANG,
+            &mut usage,
+        )
+    };
+
+    match pid {
+        0 => Ok(None),
+        -1 => match io::Error::last_os_error() {
+            e if e.kind() == io::ErrorKind::Interrupted => Ok(None),
+            e => Err(e),
+        },
+        _ => {
+            let usage = ResourceUsage {
+                // linux reports kilobytes, macOS reports bytes.
+                peak_rss_bytes: u64::try_from(usage.ru_maxrss).unwrap_or(0)
+                    * if cfg!(target_os = "macos") { 1 } else { 1024 },
+                user_time: duration_from_timeval(usage.ru_utime),
+                system_time: duration_from_timeval(usage.ru_stime),
+                wall_time: started.elapsed(),
+            };
+
+            Ok(Some((ExitStatus::from_raw(status), Some(usage))))
+        }
+    }
+}
+
+/// Resource usage is only collected on unix.
+#[cfg(not(unix))]
+pub fn try_wait(
+    child: &mut Child,
+    _started: Instant,
+) -> io::Result<Option<(ExitStatus, Option<ResourceUsage>)>> {
+    Ok(child.try_wait()?.map(|status| (status, None)))
+}
+
+#[cfg(unix)]
+fn duration_from_timeval(time: libc::timeval) -> Duration {
+    Duration::from_secs(u64::try_from(time.tv_sec).unwrap_or(0))
+        + Duration::from_micros(u64::try_from(time.tv_usec).unwrap_or(0))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::ResourceUsage;
+
+    #[test]
+    fn computes_cpu_ratio() {
+        let usage = ResourceUsage {
+            peak_rss_bytes: 0,
+            user_time: Duration::from_millis(300),
+            system_time: Duration::from_millis(100),
+            wall_time: Duration::from_millis(100),
+        };
+        assert_eq!(usage.cpu_ratio(), 4.0);
+
+        let usage = ResourceUsage {
+            wall_time: Duration::ZERO,
+            ..usage
+        };
+        assert_eq!(usage.cpu_ratio(), 0.0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_usage_of_exited_children() {
+        use super::try_wait;
+        use std::{process::Command, thread, time::Instant};
+
+        let started = Instant::now();
+        let mut child = Command::new("true").spawn().unwrap();
+
+        let (status, usage) = loop {
+            if let Some(result) = try_wait(&mut child, started).unwrap() {
+                break result;
+            }
+            thread::sleep(Duration::from_millis(1));
+        };
+
+        assert!(status.success());
+        let usage = usage.unwrap();
+        assert!(usage.peak_rss_bytes > 0);
+        assert!(usage.wall_time > Duration::ZERO);
+    }
+}
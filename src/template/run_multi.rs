@@ -1,58 +1,622 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    io::{self, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::input::{additional_inputs, InputSource};
+use crate::template::ledger::Ledger;
+use crate::template::report::{DayStatus, PartReport};
+use crate::template::resources::ResourceUsage;
+use crate::template::runner::BenchConfig;
+use crate::template::{get_bin_name, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// The time a day may run before it is killed, unless `--timeout` is passed.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The outcome of a multi-day run.
+pub struct MultiRun {
+    /// Present if the days were benched.
+    pub timings: Option<Timings>,
+    pub statuses: Vec<(Day, DayStatus)>,
+    /// The reports of all parts that finished, in day order.
+    pub parts: Vec<PartReport>,
+    /// The runs on additional inputs, if they were requested.
+    pub inputs: Vec<InputRun>,
+    /// Whether the days were built with the release profile.
+    pub is_release: bool,
+}
+
+impl MultiRun {
+    pub fn has_failures(&self) -> bool {
+        self.statuses.iter().any(|(_, status)| status.is_failure())
+            || self.inputs.iter().any(|run| run.status.is_failure())
+    }
+
+    /// The answer of a part on the real input, or on the additional input with the given name.
+    pub fn answer(&self, day: Day, input: Option<&str>, part: u8) -> Option<&str> {
+        let parts = match input {
+            Some(name) => {
+                &self
+                    .inputs
+                    .iter()
+                    .find(|run| run.day == day && run.name == name)?
+                    .parts
+            }
+            None => &self.parts,
+        };
+
+        parts
+            .iter()
+            .find(|p| p.day == day && p.part == part)?
+            .answer
+            .as_deref()
+    }
+}
 
-    let mut need_space = false;
+/// The outcome of running a day on one of its additional inputs.
+pub struct InputRun {
+    pub day: Day,
+    /// The name of the input file in `inputs/<day>/`, without extension.
+    pub name: String,
+    pub status: DayStatus,
+    pub parts: Vec<PartReport>,
+}
 
+/// Settings shared by all child invocations of a run.
+#[derive(Clone, Copy)]
+pub struct RunSettings<'a> {
+    year: Year,
+    bench_config: Option<&'a BenchConfig>,
+    is_release: bool,
+    timeout: Duration,
+    /// The executable of the `registry` binary, if it could be built.
+    registry: Option<&'a Path>,
+    /// Measure heap usage by running binaries built with the `dhat` profile.
+    memory: bool,
+}
+
+/// Runs the solutions of a set of days and prints a summary of their statuses.
+/// The days run in-process via the `registry` binary. If it is not available, each day runs in its own binary.
+/// With more than one job, days run concurrently and their output is printed in day order once a day finishes.
+/// If a bench config is passed, the days are benched serially and their timings returned.
+/// A day that runs longer than `timeout` is killed.
+/// With `multi_input`, each day additionally runs on every input in `inputs/<day>/`, one after another.
+/// With `resources`, each day runs in a process of its own, so the resources it uses can be measured.
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench_config: Option<&BenchConfig>,
+    jobs: usize,
+    timeout: Duration,
+    multi_input: bool,
+    resources: bool,
+) -> MultiRun {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .collect();
+
+    let registry = child_commands::build_registry(is_release).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        None
+    });
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    if registry.is_none() {
+        eprintln!("Could not build the solution registry, running each day separately.\n");
+    }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let settings = RunSettings {
+        year,
+        bench_config,
+        is_release,
+        timeout,
+        registry: registry.as_deref(),
+        memory: false,
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    // without submissions, wrong answers are not recognized but the days still run.
+    let ledger = Ledger::read_from_file().unwrap_or_else(|e| {
+        eprintln!("failed to read submissions: {e}");
+        Ledger::default()
+    });
+
+    // timed runs are not parallelized to keep measurements clean.
+    let Outcome {
+        parts,
+        statuses,
+        usage,
+    } = if bench_config.is_none() && jobs > 1 {
+        run_parallel(settings, &days, jobs, &ledger)
+    } else if let Some(registry) = settings.registry.filter(|_| !resources) {
+        run_registry(settings, registry, &days, &ledger)
+    } else {
+        run_each(settings, &days, &ledger)
+    };
+
+    let inputs = if multi_input {
+        run_additional_inputs(settings, &days)
+    } else {
+        vec![]
+    };
 
-    if is_timed {
-        let timings = Timings { data: timings };
+    let timings = bench_config.map(|_| {
+        let timings = Timings {
+            data: days
+                .iter()
+                .filter(|day| Path::new(&get_path_for_bin(year, **day)).exists())
+                .map(|day| {
+                    let mut timing = child_commands::timing_from_reports(&parts, *day);
+                    timing.resources = usage
+                        .iter()
+                        .find(|(d, _)| d == day)
+                        .map(|(_, usage)| usage.clone());
+                    timing
+                })
+                .collect(),
+        };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        timings
+    });
+
+    if !inputs.is_empty() {
+        print_input_durations(&days, &parts, &inputs);
+    }
+
+    print_summary(&statuses, &inputs);
+
+    MultiRun {
+        timings,
+        statuses,
+        parts,
+        inputs,
+        is_release,
+    }
+}
+
+/// Runs each day once under the heap profiler and returns the reports of its parts, including their heap usage.
+/// The days are built with the `dhat` profile and feature, which the registry does not support.
+pub fn run_memory(year: Year, days_to_run: &HashSet<Day>, timeout: Duration) -> Vec<PartReport> {
+    let days: Vec<Day> = all_days(year)
+        .filter(|day| days_to_run.contains(day))
+        .collect();
+
+    let settings = RunSettings {
+        year,
+        bench_config: None,
+        is_release: true,
+        timeout,
+        registry: None,
+        memory: true,
+    };
+
+    println!("\n{ANSI_BOLD}Memory{ANSI_RESET}");
+    println!("------\n");
+
+    // allocations do not affect the status, it is reported by the timed run.
+    run_each(settings, &days, &Ledger::default()).parts
+}
+
+/// What running a set of days produced.
+#[derive(Default)]
+struct Outcome {
+    /// The reports of all parts that finished, in day order.
+    parts: Vec<PartReport>,
+    statuses: Vec<(Day, DayStatus)>,
+    /// The resources used by days that ran in a process of their own.
+    usage: Vec<(Day, ResourceUsage)>,
+}
+
+impl Outcome {
+    fn push(&mut self, day: Day, run: DayRun) {
+        self.statuses.push((day, run.status));
+        self.parts.extend(run.parts);
+
+        if let Some(usage) = run.usage {
+            self.usage.push((day, usage));
+        }
+    }
+}
+
+/// Runs days back to back in the `registry` binary.
+/// If the registry is killed or crashes, the day it was running is marked and the remaining days run in a new registry.
+fn run_registry(settings: RunSettings, registry: &Path, days: &[Day], ledger: &Ledger) -> Outcome {
+    let mut reports = vec![];
+    let mut statuses = vec![];
+    let mut remaining = days;
+
+    while !remaining.is_empty() {
+        if !statuses.is_empty() {
+            println!();
+        }
+
+        let output = match child_commands::run_registry(
+            registry,
+            settings,
+            remaining,
+            &InputSource::Real,
+            false,
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Could not run the solution registry: {e}");
+                statuses.extend(remaining.iter().map(|day| (*day, DayStatus::Panicked)));
+                break;
+            }
+        };
+
+        // the registry reports finished days in order.
+        let finished = output.reports.days.len().min(remaining.len());
+
+        for day_report in &output.reports.days {
+            let status = child_commands::classify(
+                settings.year,
+                day_report.day,
+                &output.exit,
+                Some(day_report.status),
+                &output.reports.parts,
+                ledger,
+            );
+            statuses.push((day_report.day, status));
+        }
+
+        reports.extend(output.reports.parts);
+        remaining = &remaining[finished..];
+
+        // the registry stopped while running the next day.
+        if let Some(day) = remaining.first() {
+            if output.exit == child_commands::Exit::TimedOut {
+                print_timeout(settings.timeout);
+                statuses.push((*day, DayStatus::TimedOut));
+            } else {
+                statuses.push((*day, DayStatus::Panicked));
+            }
+            remaining = &remaining[1..];
+        }
+    }
+
+    // the registry shares its resources between days.
+    Outcome {
+        parts: reports,
+        statuses,
+        usage: vec![],
+    }
+}
+
+/// Runs each day in a process of its own, either the `registry` or its solution binary.
+fn run_each(settings: RunSettings, days: &[Day], ledger: &Ledger) -> Outcome {
+    let mut outcome = Outcome::default();
+
+    for (i, day) in days.iter().enumerate() {
+        print_run_header(settings, *day, &InputSource::Real, i > 0);
+
+        let run = run_day(settings, *day, &InputSource::Real, false, ledger);
+        print_day_run(&run, settings.timeout);
+
+        outcome.push(*day, run);
+    }
+
+    outcome
+}
+
+/// Runs each scaffolded day on its additional inputs.
+/// Answers are not checked against the submission ledger, as it only knows answers for the real input.
+fn run_additional_inputs(settings: RunSettings, days: &[Day]) -> Vec<InputRun> {
+    let ledger = Ledger::default();
+    let mut runs = vec![];
+
+    for day in days {
+        if !Path::new(&get_path_for_bin(settings.year, *day)).exists() {
+            continue;
+        }
+
+        for (name, path) in additional_inputs(settings.year, *day) {
+            let input = InputSource::File(path);
+
+            print_run_header(settings, *day, &input, true);
+
+            let run = run_day(settings, *day, &input, false, &ledger);
+            print_day_run(&run, settings.timeout);
+
+            runs.push(InputRun {
+                day: *day,
+                name,
+                status: run.status,
+                parts: run.parts,
+            });
+        }
+    }
+
+    runs
+}
+
+/// Runs days on a number of worker threads, each day in its own child process.
+/// Output is buffered and printed in day order.
+fn run_parallel(settings: RunSettings, days: &[Day], jobs: usize, ledger: &Ledger) -> Outcome {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut outcome = Outcome::default();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(day) = days.get(i) else {
+                    break;
+                };
+
+                let run = run_day(settings, *day, &InputSource::Real, true, ledger);
+                sender.send((i, run)).unwrap();
+            });
+        }
+
+        drop(sender);
+
+        // print finished days as soon as all days before them are printed.
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (i, run) in receiver {
+            pending.insert(i, run);
+
+            while let Some(run) = pending.remove(&next_to_print) {
+                let day = days[next_to_print];
+
+                print_run_header(settings, day, &InputSource::Real, next_to_print > 0);
+
+                print_day_run(&run, settings.timeout);
+
+                outcome.push(day, run);
+                next_to_print += 1;
+            }
+        }
+    });
+
+    outcome
+}
+
+/// The result of running a single day in its own child process.
+struct DayRun {
+    status: DayStatus,
+    parts: Vec<PartReport>,
+    /// Output of the day, if it was captured.
+    stdout: String,
+    stderr: String,
+    /// Present if the child exited and the operating system reports resource usage.
+    usage: Option<ResourceUsage>,
+}
+
+/// Runs a single day on an input in the `registry` or its solution binary.
+/// If `capture` is set, output is buffered in the result instead of forwarded.
+fn run_day(
+    settings: RunSettings,
+    day: Day,
+    input: &InputSource,
+    capture: bool,
+    ledger: &Ledger,
+) -> DayRun {
+    let year = settings.year;
+
+    let failed = |status: DayStatus, message: String| DayRun {
+        status,
+        parts: vec![],
+        stdout: String::new(),
+        stderr: message,
+        usage: None,
+    };
+
+    let output = if let Some(registry) = settings.registry {
+        child_commands::run_registry(registry, settings, &[day], input, capture)
     } else {
-        None
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            let message = "Not solved.\n".to_string();
+            if !capture {
+                print!("{message}");
+            }
+
+            return DayRun {
+                status: DayStatus::Unsolved,
+                parts: vec![],
+                stdout: if capture { message } else { String::new() },
+                stderr: String::new(),
+                usage: None,
+            };
+        }
+
+        let executable = match child_commands::build(
+            &get_bin_name(year, day),
+            settings.is_release,
+            settings.memory,
+            capture,
+        ) {
+            Ok(Ok(executable)) => executable,
+            Ok(Err(errors)) => return failed(DayStatus::CompileError, errors),
+            Err(e) => return failed(DayStatus::CompileError, format!("{e}\n")),
+        };
+
+        child_commands::run_solution(&executable, settings, day, input, capture)
+    };
+
+    match output {
+        Ok(output) => DayRun {
+            status: child_commands::classify(
+                year,
+                day,
+                &output.exit,
+                output.reports.days.first().map(|d| d.status),
+                &output.reports.parts,
+                ledger,
+            ),
+            parts: output.reports.parts,
+            stdout: output.stdout,
+            stderr: output.stderr,
+            usage: output.usage,
+        },
+        Err(e) => failed(DayStatus::Panicked, format!("{e}\n")),
+    }
+}
+
+/// Prints the header of a day and its input before it runs, unless the registry prints them itself.
+fn print_run_header(settings: RunSettings, day: Day, input: &InputSource, need_space: bool) {
+    if settings.registry.is_some() {
+        if need_space {
+            println!();
+        }
+    } else {
+        print_day_header(day, need_space);
+        print_input(input);
+    }
+}
+
+fn print_day_run(run: &DayRun, timeout: Duration) {
+    let _ = io::stdout().write_all(run.stdout.as_bytes());
+    let _ = io::stderr().write_all(run.stderr.as_bytes());
+
+    if run.status == DayStatus::TimedOut {
+        print_timeout(timeout);
+    }
+
+    if let Some(usage) = &run.usage {
+        println!("{ANSI_ITALIC}Resources: {usage}{ANSI_RESET}");
+    }
+}
+
+fn print_timeout(timeout: Duration) {
+    println!("{ANSI_BOLD}Timed out{ANSI_RESET} after {timeout:.1?}.");
+}
+
+/// Prints how long each day took on each of its inputs, and the mean and maximum over all inputs.
+/// Benched parts count with their median, other parts with their first execution.
+fn print_input_durations(days: &[Day], parts: &[PartReport], inputs: &[InputRun]) {
+    let duration = |day: Day, parts: &[PartReport]| -> Duration {
+        parts
+            .iter()
+            .filter(|p| p.day == day)
+            .map(|p| p.stats.as_ref().map_or(p.duration, |s| s.median))
+            .sum()
+    };
+
+    println!("\n{ANSI_BOLD}Inputs{ANSI_RESET}");
+    println!("------");
+
+    for day in days {
+        let runs: Vec<(&str, Duration)> = std::iter::once(("default", duration(*day, parts)))
+            .chain(
+                inputs
+                    .iter()
+                    .filter(|run| run.day == *day)
+                    .map(|run| (run.name.as_str(), duration(*day, &run.parts))),
+            )
+            .collect();
+
+        if runs.len() < 2 {
+            continue;
+        }
+
+        let durations = runs
+            .iter()
+            .map(|(name, duration)| format!("{name} {duration:.1?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let max = runs.iter().map(|(_, d)| *d).max().unwrap_or_default();
+        let mean = runs.iter().map(|(_, d)| *d).sum::<Duration>() / runs.len() as u32;
+
+        println!(
+            "Day {day}  {durations}  {ANSI_ITALIC}(mean {mean:.1?}, max {max:.1?}){ANSI_RESET}"
+        );
+    }
+}
+
+fn print_summary(statuses: &[(Day, DayStatus)], inputs: &[InputRun]) {
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("-------");
+
+    let print_status = |label: String, status: &DayStatus| {
+        if status.is_failure() {
+            println!("{label}  {ANSI_BOLD}{status}{ANSI_RESET}");
+        } else {
+            println!("{label}  {status}");
+        }
+    };
+
+    for (day, status) in statuses {
+        print_status(format!("Day {day}"), status);
+
+        for run in inputs.iter().filter(|run| run.day == *day) {
+            print_status(format!("Day {day} {}", run.name), &run.status);
+        }
+    }
+
+    let statuses: Vec<&DayStatus> = statuses
+        .iter()
+        .map(|(_, status)| status)
+        .chain(inputs.iter().map(|run| &run.status))
+        .collect();
+
+    let count = |f: fn(&DayStatus) -> bool| statuses.iter().filter(|s| f(s)).count();
+
+    println!(
+        "\n{} ok, {} unsolved, {} failed",
+        count(|s| *s == DayStatus::Ok),
+        count(|s| *s == DayStatus::Unsolved),
+        count(|s| s.is_failure()),
+    );
+}
+
+pub fn print_day_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Prints the input file a day runs on, if it is not the real input.
+pub fn print_input(input: &InputSource) {
+    if let InputSource::File(path) = input {
+        println!("{ANSI_ITALIC}Input: {}{ANSI_RESET}", path.display());
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
+    Report(String),
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Report(e) => write!(f, "could not read report: {e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -60,198 +624,538 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as reading the reports they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{Error, RunSettings};
+    use crate::template::input::InputSource;
+    use crate::template::ledger::Ledger;
+    use crate::template::report::{
+        read_reports, DayStatus, PartReport, PartStatus, Reports, PARSE_PART, REPORT_FILE_ENV,
+    };
+    use crate::template::resources::{self, ResourceUsage};
+    use crate::template::{get_bin_name, Day, Year};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        collections::HashMap,
+        env, fs,
+        io::{BufRead, Read},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        str::FromStr,
         thread,
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    /// How often a running child is checked for progress.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    /// How a child invocation ended.
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Exit {
+        Success,
+        Failure,
+        TimedOut,
+    }
 
-        if is_release {
-            args.push("--release");
+    /// The result of a child invocation.
+    /// `stdout` and `stderr` are only filled if the output was captured.
+    pub struct ChildOutput {
+        pub exit: Exit,
+        pub stdout: String,
+        pub stderr: String,
+        pub reports: Reports,
+        /// The resources the child used, if it exited and the operating system reports them.
+        pub usage: Option<ResourceUsage>,
+    }
+
+    /// Builds a binary and returns the path of its executable.
+    /// With `dhat`, the binary is built with the `dhat` profile and the heap profiler enabled.
+    /// If the binary does not compile, returns the compiler errors if `capture` is set, forwards them otherwise.
+    pub fn build(
+        bin: &str,
+        is_release: bool,
+        dhat: bool,
+        capture: bool,
+    ) -> Result<Result<PathBuf, String>, Error> {
+        let mut cmd = Command::new("cargo");
+        cmd.args([
+            "build",
+            "--quiet",
+            "--bin",
+            bin,
+            "--message-format=json-render-diagnostics",
+        ])
+        .stdout(Stdio::piped());
+
+        if dhat {
+            cmd.args(["--profile", "dhat", "--features", "dhat-heap"]);
+        } else if is_release {
+            cmd.arg("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        // count allocations in the children if the command itself was built to count them.
+        if cfg!(feature = "count-allocations") {
+            cmd.args(["--features", "count-allocations"]);
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        if capture {
+            cmd.stderr(Stdio::piped());
+        }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let output = cmd.output()?;
+        let errors = String::from_utf8_lossy(&output.stderr).into_owned();
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        if !output.status.success() {
+            return Ok(Err(errors));
+        }
 
-        let mut output = vec![];
+        // cargo describes every artifact as JSON, look for the executable of the binary.
+        let executable = output
+            .stdout
+            .lines()
+            .map_while(Result::ok)
+            .find_map(|line| {
+                let json = JsonValue::from_str(&line).ok()?;
+                let artifact = json.get::<HashMap<String, JsonValue>>()?;
+                let target = artifact
+                    .get("target")?
+                    .get::<HashMap<String, JsonValue>>()?;
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                if target.get("name")?.get::<String>()? != bin {
+                    return None;
+                }
+
+                artifact
+                    .get("executable")?
+                    .get::<String>()
+                    .map(PathBuf::from)
             });
-        });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        Ok(executable.ok_or(errors))
+    }
+
+    /// Builds the `registry` binary. Returns `None` if it is not available.
+    pub fn build_registry(is_release: bool) -> Result<Option<PathBuf>, Error> {
+        // solutions set up their own global allocator for dhat and cannot share a binary.
+        if cfg!(feature = "dhat-heap") {
+            return Ok(None);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        // compiler errors are shown when the affected day is built on its own.
+        Ok(build("registry", is_release, false, true)?.ok())
+    }
+
+    /// Run the `registry` binary for a set of days and collect the reports of their parts.
+    pub fn run_registry(
+        executable: &Path,
+        settings: RunSettings,
+        days: &[Day],
+        input: &InputSource,
+        capture: bool,
+    ) -> Result<ChildOutput, Error> {
+        let days_str = days
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut args = vec![
+            "--year".to_string(),
+            settings.year.to_string(),
+            "--days".to_string(),
+            days_str.clone(),
+        ];
 
-        Ok(output)
+        if let Some(bench_config) = settings.bench_config {
+            args.push("--time".into());
+            args.extend(bench_config.to_args());
+        }
+
+        args.extend(input.to_args());
+
+        run_with_report(
+            executable,
+            &args,
+            &format!("registry-{}-{days_str}", settings.year),
+            capture,
+            settings.timeout,
+        )
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    /// Run the solution bin for a given day and collect the reports of its parts.
+    pub fn run_solution(
+        executable: &Path,
+        settings: RunSettings,
+        day: Day,
+        input: &InputSource,
+        capture: bool,
+    ) -> Result<ChildOutput, Error> {
+        let mut args = vec![];
+
+        if let Some(bench_config) = settings.bench_config {
+            // mirror `--time` flag and bench settings to child invocations.
+            args.push("--time".into());
+            args.extend(bench_config.to_args());
+        }
+
+        if settings.memory {
+            args.push("--memory".into());
+        }
+
+        args.extend(input.to_args());
+
+        run_with_report(
+            executable,
+            &args,
+            &get_bin_name(settings.year, day),
+            capture,
+            settings.timeout,
+        )
+    }
+
+    /// Runs an executable and reads the reports it emits.
+    /// The child is killed if it does not finish a day within `timeout`.
+    /// If `capture` is set, the child's output is buffered instead of forwarded.
+    fn run_with_report(
+        executable: &Path,
+        args: &[String],
+        name: &str,
+        capture: bool,
+        timeout: Duration,
+    ) -> Result<ChildOutput, Error> {
+        // the child appends one record per part to this file, its stdout is left to humans.
+        let report_path =
+            env::temp_dir().join(format!("aoc-report-{}-{name}.jsonl", process::id()));
+        let _ = fs::remove_file(&report_path);
+
+        let mut cmd = Command::new(executable);
+        cmd.args(args).env(REPORT_FILE_ENV, &report_path);
+
+        if capture {
+            cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+        }
+
+        let started = Instant::now();
+        let mut child = cmd.spawn()?;
+
+        let read_pipe = |pipe: Option<Box<dyn Read + Send>>| {
+            thread::spawn(move || {
+                let mut buf = String::new();
+                if let Some(mut pipe) = pipe {
+                    let _ = pipe.read_to_string(&mut buf);
+                }
+                buf
+            })
+        };
+
+        let stdout = read_pipe(child.stdout.take().map(|x| Box::new(x) as _));
+        let stderr = read_pipe(child.stderr.take().map(|x| Box::new(x) as _));
+
+        // the timeout restarts whenever the child reports a finished day.
+        let mut deadline = Instant::now() + timeout;
+        let mut finished_days = 0;
+
+        let mut usage = None;
+
+        let exit = loop {
+            if let Some((status, child_usage)) = resources::try_wait(&mut child, started)? {
+                usage = child_usage;
+                break if status.success() {
+                    Exit::Success
+                } else {
+                    Exit::Failure
+                };
+            }
+
+            let days = read_reports(&report_path).map_or(finished_days, |r| r.days.len());
+            if days > finished_days {
+                finished_days = days;
+                deadline = Instant::now() + timeout;
+            }
+
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                break Exit::TimedOut;
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        let reports = read_reports(&report_path).map_err(Error::Report);
+        let _ = fs::remove_file(&report_path);
+
+        Ok(ChildOutput {
+            exit,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+            reports: reports?,
+            usage,
+        })
+    }
+
+    /// Determines the status of a day from how its child exited, the day record and the reports of its parts.
+    /// Answers are checked against the submission ledger.
+    pub fn classify(
+        year: Year,
+        day: Day,
+        exit: &Exit,
+        day_status: Option<DayStatus>,
+        parts: &[PartReport],
+        ledger: &Ledger,
+    ) -> DayStatus {
+        let parts: Vec<&PartReport> = parts.iter().filter(|p| p.day == day).collect();
+        let has_error = parts.iter().any(|p| p.status == PartStatus::Error);
+
+        match (day_status, exit) {
+            (Some(DayStatus::Panicked), _) => return DayStatus::Panicked,
+            // solution binaries exit with a failure if they cannot parse their input.
+            (None, Exit::Failure) if !has_error => return DayStatus::Panicked,
+            (None, Exit::TimedOut) => return DayStatus::TimedOut,
+            _ => {}
+        }
+
+        let is_wrong = |p: &&PartReport| {
+            p.answer
+                .as_ref()
+                .is_some_and(|answer| ledger.is_wrong(year, day, p.part, answer))
+        };
+
+        if parts.iter().any(is_wrong) {
+            DayStatus::WrongAnswer
+        } else if has_error {
+            DayStatus::Error
+        } else if parts.is_empty() || parts.iter().any(|p| p.status != PartStatus::Ok) {
+            DayStatus::Unsolved
+        } else {
+            DayStatus::Ok
+        }
+    }
+
+    /// Collects the timings of a day's benched parts. Parts that were not benched or not solved are left empty.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn timing_from_reports(reports: &[PartReport], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_stats: None,
+            parse_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            resources: None,
             total_nanos: 0_f64,
         };
 
-        output
+        reports
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+            .filter(|report| report.day == day && report.status == PartStatus::Ok)
+            .filter_map(|report| Some((report.part, report.stats.as_ref()?)))
+            .for_each(|(part, stats)| {
+                let timing_str = Some(format!("{:.1?}", stats.median));
+                match part {
+                    PARSE_PART => {
+                        timings.parse = timing_str;
+                        timings.parse_stats = Some(stats.clone());
+                    }
+                    1 => {
+                        timings.part_1 = timing_str;
+                        timings.part_1_stats = Some(stats.clone());
+                    }
+                    2 => {
+                        timings.part_2 = timing_str;
+                        timings.part_2_stats = Some(stats.clone());
+                    }
+                    _ => return,
                 }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
+                timings.total_nanos += stats.median.as_nanos() as f64;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+    #[cfg(feature = "test_lib")]
+    mod tests {
+        use std::time::Duration;
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+        use super::{classify, timing_from_reports, Exit};
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
+        use crate::template::ledger::{Ledger, Verdict};
+        use crate::template::report::{DayStatus, PartReport, PartStatus};
+        use crate::template::runner::BenchStats;
+        use crate::{day, year};
+
+        fn report(part: u8, answer: Option<&str>, median: Option<Duration>) -> PartReport {
+            PartReport {
+                day: day!(1),
+                part,
+                answer: answer.map(Into::into),
+                status: if answer.is_some() {
+                    PartStatus::Ok
+                } else {
+                    PartStatus::Unsolved
+                },
+                duration: Duration::from_millis(5),
+                stats: median.map(|median| BenchStats {
+                    min: median,
+                    median,
+                    mean: median,
+                    stddev: Duration::ZERO,
+                    p95: median,
+                    samples: 100,
+                    outliers: 0,
+                }),
+                memory: None,
+            }
+        }
+
+        #[test]
+        fn reads_timings_from_reports() {
+            let res = timing_from_reports(
+                &[
+                    report(1, Some("0"), Some(Duration::from_nanos(74))),
+                    report(2, Some("10"), Some(Duration::from_micros(74130))),
+                ],
+                day!(1),
             );
-        }};
-    }
+            assert_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_1_stats.unwrap().samples, 100);
+        }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
+        #[test]
+        fn reads_parse_timings_from_reports() {
+            let mut parse = report(0, None, Some(Duration::from_micros(5)));
+            parse.status = PartStatus::Ok;
 
-        use crate::day;
+            let res = timing_from_reports(
+                &[parse, report(1, Some("0"), Some(Duration::from_micros(1)))],
+                day!(1),
+            );
+            assert_eq!(res.total_nanos, 6000_f64);
+            assert_eq!(res.parse.unwrap(), "5.0µs");
+            assert_eq!(res.part_1.unwrap(), "1.0µs");
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn ignores_answers_that_look_like_timings() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(
+                        1,
+                        Some("@ @ @ ( ) ms (2s @ 5 samples)"),
+                        Some(Duration::from_secs(2)),
+                    ),
+                    report(2, Some("10s (100ms @ 1 samples)"), None),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.total_nanos, 2000000000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert!(res.part_2.is_none());
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn reads_timings_of_a_single_day() {
+            let mut other_day = report(1, Some("1"), Some(Duration::from_millis(3)));
+            other_day.day = day!(2);
+
+            let res = timing_from_reports(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    other_day,
+                    report(2, Some("2"), Some(Duration::from_millis(1))),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.total_nanos, 1000000_f64);
+            assert!(res.part_1.is_none());
+            assert_eq!(res.part_2.unwrap(), "1.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn skips_unsolved_parts() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    report(1, None, Some(Duration::from_millis(1))),
+                    report(2, None, None),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.total_nanos, 0_f64);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
+        }
+
+        #[test]
+        fn classifies_failed_children() {
+            let ledger = Ledger::default();
+            let parts = [report(1, Some("1"), None)];
+            let classify = |exit, day_status| {
+                classify(year!(2024), day!(1), &exit, day_status, &parts, &ledger)
+            };
+
+            assert_eq!(classify(Exit::TimedOut, None), DayStatus::TimedOut);
+            assert_eq!(classify(Exit::Failure, None), DayStatus::Panicked);
+            assert_eq!(
+                classify(Exit::Failure, Some(DayStatus::Panicked)),
+                DayStatus::Panicked
+            );
+            // a registry that finished the day before being killed.
+            assert_eq!(classify(Exit::TimedOut, Some(DayStatus::Ok)), DayStatus::Ok);
+        }
+
+        #[test]
+        fn classifies_errors() {
+            let ledger = Ledger::default();
+            let mut parse = report(0, None, None);
+            parse.status = PartStatus::Error;
+            let mut part_two = report(2, None, None);
+            part_two.status = PartStatus::Error;
+
+            let classify = |exit, parts: &[PartReport]| {
+                classify(year!(2024), day!(1), &exit, None, parts, &ledger)
+            };
+
+            assert_eq!(
+                classify(Exit::Success, &[report(1, Some("1"), None), part_two]),
+                DayStatus::Error
+            );
+            // a solution binary that could not parse its input.
+            assert_eq!(classify(Exit::Failure, &[parse]), DayStatus::Error);
+        }
+
+        #[test]
+        fn classifies_answers() {
+            let mut ledger = Ledger::default();
+            ledger.record(year!(2024), day!(1), 2, "10", Verdict::Correct);
+
+            let classify = |parts: &[PartReport]| {
+                classify(year!(2024), day!(1), &Exit::Success, None, parts, &ledger)
+            };
+
+            assert_eq!(
+                classify(&[report(1, Some("1"), None), report(2, Some("10"), None)]),
+                DayStatus::Ok
+            );
+            assert_eq!(
+                classify(&[report(1, Some("1"), None), report(2, Some("11"), None)]),
+                DayStatus::WrongAnswer
+            );
+            assert_eq!(
+                classify(&[report(1, None, None), report(2, Some("10"), None)]),
+                DayStatus::Unsolved
+            );
+            assert_eq!(classify(&[]), DayStatus::Unsolved);
         }
     }
 }
@@ -1,63 +1,524 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
+use std::convert::Infallible;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::parse::ParseError;
+use crate::template::answers::Answers;
+use crate::template::input::InputSource;
+use crate::template::ledger::{Ledger, Verdict};
+use crate::template::report::{part_label, PartReport, PartStatus, PARSE_PART};
+use crate::template::{aoc_client, Day, Year, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{ANSI_BOLD, ANSI_RED};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// The return value of a solution part.
+/// Parts return `Option<T>`, where `None` marks them as unsolved, or `Result<T, E>` if they can fail.
+pub trait PartOutput {
+    type Answer: Display;
+    type Error: Display;
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    /// The answer, if the part was solved.
+    fn answer(&self) -> Option<&Self::Answer>;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    /// The error, if the part failed.
+    fn error(&self) -> Option<&Self::Error>;
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    fn status(&self) -> PartStatus {
+        match (self.answer(), self.error()) {
+            (_, Some(_)) => PartStatus::Error,
+            (Some(_), None) => PartStatus::Ok,
+            (None, None) => PartStatus::Unsolved,
+        }
+    }
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+    type Error = Infallible;
+
+    fn answer(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    fn error(&self) -> Option<&Infallible> {
+        None
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    type Answer = T;
+    type Error = E;
+
+    fn answer(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+
+    fn error(&self) -> Option<&E> {
+        self.as_ref().err()
+    }
+}
+
+/// Runs a solution part as the entry point of a solution binary.
+/// Benches the part if `--time` is passed and submits the answer if `--submit <part>` is passed.
+pub fn run_part<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    let args: Vec<String> = env::args().collect();
+    let bench_config = args
+        .iter()
+        .any(|x| x == "--time")
+        .then(|| BenchConfig::from_args(&args));
+
+    let report = run_part_with(func, input, day, part, bench_config.as_ref());
+
+    if let Some(answer) = report.answer {
+        submit_result(answer, year, day, part);
+    }
+}
+
+/// Runs and prints a solution part, benching it if a config is passed and the part did not fail.
+/// The resulting report is written to the report file, if one was requested.
+pub fn run_part_with<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    day: Day,
+    part: u8,
+    bench_config: Option<&BenchConfig>,
+) -> PartReport {
+    let part_str = part_label(part);
+
+    let (result, duration, memory) = run_timed(&func, input, |result| {
+        print_result(result, &part_str, "");
+    });
+
+    let stats = match (result.status(), bench_config) {
+        (PartStatus::Error, _) | (_, None) => None,
+        (_, Some(config)) => Some(bench(&func, input, &duration, config)),
+    };
+
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, stats.as_ref(), memory.as_ref()),
+    );
+
+    let report = PartReport {
+        day,
+        part,
+        answer: result.answer().map(ToString::to_string),
+        status: result.status(),
+        duration,
+        stats,
+        memory,
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("failed to write report: {e}");
+    }
+
+    report
+}
+
+/// The output of a `parse` function that returns a `Result`, see [`ParseValue`].
+pub struct ParseResultOutput;
+
+impl ParseResultOutput {
+    pub fn into_result<T, E>(self, output: Result<T, E>) -> Result<T, E> {
+        output
+    }
+}
+
+/// The output of a `parse` function that returns the parsed input directly, see [`ParseValue`].
+pub struct ParseValueOutput;
+
+impl ParseValueOutput {
+    pub fn into_result<T>(self, output: T) -> Result<T, Infallible> {
+        Ok(output)
+    }
+}
+
+/// Tells apart `parse` functions that return a `Result<T, E>` from those that return a plain `T`.
+/// `solution!` calls `(&output).parse_kind()`: method resolution picks this impl for a `Result`
+/// before it falls back to [`ParseValue`], which matches any reference.
+pub trait ParseResult {
+    fn parse_kind(&self) -> ParseResultOutput {
+        ParseResultOutput
+    }
+}
+
+impl<T, E: Display> ParseResult for Result<T, E> {}
+
+/// The fallback of [`ParseResult`] for `parse` functions that cannot fail.
+pub trait ParseValue {
+    fn parse_kind(&self) -> ParseValueOutput {
+        ParseValueOutput
+    }
+}
+
+impl<T> ParseValue for &T {}
+
+/// Runs the parse stage as the entry point of a solution binary. Benches it if `--time` is passed.
+/// Exits if the input cannot be parsed.
+pub fn run_parse<'a, P, E: Display + 'static>(
+    func: impl Fn(&'a str) -> Result<P, E>,
+    input: &'a str,
+    day: Day,
+) -> P {
+    let args: Vec<String> = env::args().collect();
+    let bench_config = args
+        .iter()
+        .any(|x| x == "--time")
+        .then(|| BenchConfig::from_args(&args));
+
+    run_parse_with(func, input, day, bench_config.as_ref())
+        .0
+        .unwrap_or_else(|_| process::exit(1))
+}
+
+/// Runs and prints the parse stage of a solution, benching it if a config is passed and the input could be parsed.
+/// The stage is reported like a part, with [`PARSE_PART`] as part number and without answer.
+pub fn run_parse_with<'a, P, E: Display + 'static>(
+    func: impl Fn(&'a str) -> Result<P, E>,
+    input: &'a str,
+    day: Day,
+    bench_config: Option<&BenchConfig>,
+) -> (Result<P, E>, PartReport) {
+    let label = part_label(PARSE_PART);
+
+    let (parsed, duration, memory) = run_timed(&func, input, |_| {
+        print!("{label}:");
+    });
+
+    let stats = match (&parsed, bench_config) {
+        (Ok(_), Some(config)) => Some(bench(&func, input, &duration, config)),
+        _ => None,
+    };
+
+    print!("\r");
+    match &parsed {
+        Ok(_) => println!(
+            "{label}:{}",
+            format_duration(&duration, stats.as_ref(), memory.as_ref())
+        ),
+        Err(e) => print_parse_error(&label, e),
+    }
+
+    let report = PartReport {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        status: if parsed.is_ok() {
+            PartStatus::Ok
+        } else {
+            PartStatus::Error
+        },
+        duration,
+        stats,
+        memory,
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("failed to write report: {e}");
+    }
+
+    (parsed, report)
+}
+
+/// Prints and reports an input that could not be read, e.g. because its parameters are malformed.
+/// It is reported as a failed parse stage, so the day counts as an error.
+pub fn report_input_error(error: &str, day: Day) -> PartReport {
+    println!(
+        "{}: {ANSI_RED}✖ {error}{ANSI_RESET}",
+        part_label(PARSE_PART)
+    );
+
+    let report = PartReport {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        status: PartStatus::Error,
+        duration: Duration::ZERO,
+        stats: None,
+        memory: None,
+    };
+
+    if let Err(e) = report.emit() {
+        eprintln!("failed to write report: {e}");
+    }
+
+    report
+}
+
+/// Prints why the input could not be parsed, with the offending line if the error is a [`ParseError`].
+fn print_parse_error<E: Display + 'static>(label: &str, error: &E) {
+    println!("{label}: {ANSI_RED}✖ {error}{ANSI_RESET}");
+
+    let snippet = (error as &dyn Any)
+        .downcast_ref::<ParseError>()
+        .map(ParseError::snippet);
+
+    for line in snippet.iter().flat_map(|snippet| snippet.lines()) {
+        println!("    {line}");
+    }
+}
+
+/// Summary statistics of the samples taken while benching a solution part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub samples: u128,
+    /// Samples further than 1.5 interquartile ranges away from the middle half.
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Computes the statistics of a non-empty set of samples.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let percentile = |p: f64| {
+            let rank = (p * sorted.len() as f64).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (nanos.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = percentile(0.25).as_nanos() as f64;
+        let q3 = percentile(0.75).as_nanos() as f64;
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|x| **x < q1 - fence || **x > q3 + fence)
+            .count();
+
+        Self {
+            min: sorted[0],
+            median: percentile(0.5),
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+            p95: percentile(0.95),
+            samples: sorted.len() as u128,
+            outliers,
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "median {:.1?}, mean {:.1?} ± {:.1?}, min {:.1?}, p95 {:.1?}, {} outliers @ {} samples",
+            self.median, self.mean, self.stddev, self.min, self.p95, self.outliers, self.samples
+        )
+    }
+}
+
+/// Heap usage of a single execution of a solution part, as measured by the heap profiler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// The number of allocations.
+    pub allocations: u64,
+    pub total_bytes: u64,
+    /// The most memory that was allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+impl MemoryStats {
+    /// Sums up the stats of consecutive stages. The peak is that of the stage with the highest peak.
+    pub fn combine(stats: impl IntoIterator<Item = Self>) -> Option<Self> {
+        stats.into_iter().reduce(|a, b| Self {
+            allocations: a.allocations + b.allocations,
+            total_bytes: a.total_bytes + b.total_bytes,
+            peak_bytes: a.peak_bytes.max(b.peak_bytes),
+        })
+    }
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} total, {} peak",
+            self.allocations,
+            format_bytes(self.total_bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1} {unit}")
+}
+
+/// Controls how long a solution part is benched for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// The approximate time spent taking samples.
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads the `--bench-time <ms>`, `--min-samples <n>` and `--max-samples <n>` flags.
+    pub fn from_args(args: &[String]) -> Self {
+        let value = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            args.get(index + 1)?.parse::<u128>().ok()
+        };
+
+        let default = Self::default();
+
+        #[allow(clippy::cast_possible_truncation)]
+        Self {
+            budget: value("--bench-time")
+                .map_or(default.budget, |ms| Duration::from_millis(ms as u64)),
+            min_samples: value("--min-samples").unwrap_or(default.min_samples),
+            max_samples: value("--max-samples").unwrap_or(default.max_samples),
+        }
+    }
+
+    /// Converts the config to flags that can be passed to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--bench-time".into(),
+            self.budget.as_millis().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+        ]
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Runs a solution part once and passes the result to a hook, e.g. to print it before the part is benched.
+///
+/// The heap usage of the execution is measured if the `dhat-heap` or `count-allocations` feature is enabled.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<MemoryStats>) {
     let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
-
-        func(input)
-    };
+    let (result, memory) = profile_memory(|| func(input));
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    (result, base_time, memory)
+}
+
+/// Runs a function under the heap profiler.
+/// If `--memory` is passed, its heap usage is returned. Otherwise, the profile is written to `dhat-heap.json`.
+#[cfg(feature = "dhat-heap")]
+fn profile_memory<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    if !env::args().any(|x| x == "--memory") {
+        let _profiler = dhat::Profiler::new_heap();
+        return (func(), None);
+    }
+
+    // testing mode neither prints nor writes the profile.
+    let _profiler = dhat::Profiler::builder().testing().build();
+    let result = func();
+    let stats = dhat::HeapStats::get();
+
+    let memory = MemoryStats {
+        allocations: stats.total_blocks,
+        total_bytes: stats.total_bytes,
+        peak_bytes: stats.max_bytes as u64,
     };
 
-    (result, run.0, run.1)
+    (result, Some(memory))
+}
+
+/// Runs a function and counts its allocations.
+#[cfg(all(feature = "count-allocations", not(feature = "dhat-heap")))]
+fn profile_memory<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    let measurement = crate::template::allocations::Measurement::start();
+    let result = func();
+    (result, Some(measurement.stop()))
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Heap usage is only measured if the `dhat-heap` or `count-allocations` feature is enabled.
+#[cfg(not(any(feature = "dhat-heap", feature = "count-allocations")))]
+fn profile_memory<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    (func(), None)
+}
+
+/// Benches a function, by default for approx. 1 second of execution time or 10 samples, whatever takes longer.
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = (config.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(config.min_samples.max(1), config.max_samples.max(1));
+
+    // warm up caches and branch predictors before taking samples.
+    let warmup_iterations = (bench_iterations / 10).clamp(1, 100);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,34 +528,27 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(
+    duration: &Duration,
+    stats: Option<&BenchStats>,
+    memory: Option<&MemoryStats>,
+) -> String {
+    let memory = memory.map_or(String::new(), |memory| format!(" [{memory}]"));
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+    match stats {
+        Some(stats) => format!(" ({stats}){memory}"),
+        None => format!(" ({duration:.1?}){memory}"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result<R: PartOutput>(result: &R, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
+    match (result.answer(), result.error()) {
+        (Some(result), _) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -114,7 +568,16 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        (None, Some(error)) => {
+            let str = format!("{part}: {ANSI_RED}✖ {error}{ANSI_RESET}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}");
+            }
+        }
+        (None, None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -127,12 +590,14 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the submission ledger does not rule the answer out.
 fn submit_result<T: Display>(
     result: T,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<String, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,11 +620,150 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if !InputSource::from_args(&args).is_ok_and(|source| source.is_real()) {
+        println!("Not submitting: answers can only be submitted for the real puzzle input.");
+        return None;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let mut ledger = match Ledger::read_from_file() {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Not submitting: failed to read submissions: {e}");
+            return None;
+        }
+    };
+
+    if let Err(rejection) = ledger.check(year, day, part, &answer) {
+        println!("Not submitting: {rejection}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let response = aoc_client::submit(year, day, part, &answer);
+
+    match &response {
+        Ok(text) => {
+            if let Some(verdict) = Verdict::parse(text) {
+                ledger.record(year, day, part, &answer, verdict);
+                if let Err(e) = ledger.store_file() {
+                    eprintln!("failed to store submission: {e}");
+                }
+
+                if verdict == Verdict::Correct {
+                    record_answer(year, day, part, &answer);
+                }
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
+
+    Some(response)
+}
+
+/// Adds an accepted answer to the known answers that `cargo verify` checks against.
+fn record_answer(year: Year, day: Day, part: u8, answer: &str) {
+    let result = Answers::read_from_file().and_then(|mut answers| {
+        answers.insert(year, day, None, part, answer);
+        answers.store_file().map_err(|e| e.to_string())
+    });
+
+    if let Err(e) = result {
+        eprintln!("failed to store answer: {e}");
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_bytes, BenchConfig, BenchStats, MemoryStats, PartOutput};
+    use crate::template::report::PartStatus;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn computes_bench_stats() {
+        let stats = BenchStats::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.stddev.as_nanos(), 1581);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let mut samples = micros(&[10; 19]);
+        samples.push(Duration::from_millis(5));
+        let stats = BenchStats::from_samples(&samples);
+        assert_eq!(stats.median, Duration::from_micros(10));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.mean > stats.median);
+    }
+
+    #[test]
+    fn handles_single_samples() {
+        let stats = BenchStats::from_samples(&micros(&[7]));
+        assert_eq!(stats.min, stats.p95);
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn reads_bench_config_from_args() {
+        let args: Vec<String> = ["--time", "--bench-time", "250", "--max-samples", "50"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let config = BenchConfig::from_args(&args);
+        assert_eq!(config.budget, Duration::from_millis(250));
+        assert_eq!(config.min_samples, 10);
+        assert_eq!(config.max_samples, 50);
+        assert_eq!(BenchConfig::from_args(&config.to_args()), config);
+    }
+
+    #[test]
+    fn combines_memory_stats() {
+        let stats = |allocations, total_bytes, peak_bytes| MemoryStats {
+            allocations,
+            total_bytes,
+            peak_bytes,
+        };
+
+        assert_eq!(
+            MemoryStats::combine([stats(2, 100, 80), stats(3, 50, 90), stats(1, 10, 10)]),
+            Some(stats(6, 160, 90))
+        );
+        assert_eq!(MemoryStats::combine([]), None);
+    }
+
+    #[test]
+    fn reads_part_outputs() {
+        assert_eq!(Some(42).status(), PartStatus::Ok);
+        assert_eq!(None::<u32>.status(), PartStatus::Unsolved);
+
+        let solved: Result<u32, String> = Ok(42);
+        assert_eq!(solved.status(), PartStatus::Ok);
+        assert_eq!(solved.answer(), Some(&42));
+
+        let failed: Result<u32, String> = Err("no path to the exit".into());
+        assert_eq!(failed.status(), PartStatus::Error);
+        assert_eq!(failed.answer(), None);
+        assert_eq!(
+            failed.error().map(ToString::to_string).unwrap(),
+            "no path to the exit"
+        );
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
 }
@@ -1,19 +1,71 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::report::PARSE_PART;
+use crate::template::resources::ResourceUsage;
+use crate::template::runner::{BenchStats, MemoryStats};
+use crate::template::{get_data_dir, Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+static TIMINGS_FILE_NAME: &str = "timings.json";
+
+fn get_timings_path(year: Year) -> PathBuf {
+    get_data_dir(year).join(TIMINGS_FILE_NAME)
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Present if the solution has a separate parse stage.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    pub parse_stats: Option<BenchStats>,
+    /// Heap usage of each stage, present if it was measured with `cargo time --memory`.
+    pub parse_memory: Option<MemoryStats>,
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
+    /// The resources of the process that benched the day, present if it ran in a process of its own.
+    pub resources: Option<ResourceUsage>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Returns the median execution time of a part, if it was benched with statistics.
+    /// Part [`PARSE_PART`] is the parse stage.
+    pub fn median(&self, part: u8) -> Option<Duration> {
+        match part {
+            PARSE_PART => self.parse_stats.as_ref(),
+            1 => self.part_1_stats.as_ref(),
+            2 => self.part_2_stats.as_ref(),
+            _ => None,
+        }
+        .map(|stats| stats.median)
+    }
+
+    /// Sets the heap usage of a part. Part [`PARSE_PART`] is the parse stage.
+    pub fn set_memory(&mut self, part: u8, memory: Option<MemoryStats>) {
+        match part {
+            PARSE_PART => self.parse_memory = memory,
+            1 => self.part_1_memory = memory,
+            2 => self.part_2_memory = memory,
+            _ => {}
+        }
+    }
+
+    /// The heap usage of all stages of the day combined, if it was measured.
+    pub fn memory(&self) -> Option<MemoryStats> {
+        MemoryStats::combine(
+            [&self.parse_memory, &self.part_1_memory, &self.part_2_memory]
+                .into_iter()
+                .flatten()
+                .cloned(),
+        )
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,27 +74,57 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings of a year to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(get_data_dir(year))?;
+        let mut file = fs::File::create(get_timings_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings of a year from a JSON file. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_timings_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
     }
 
+    /// Rehydrate the timings of every year that has stored timings, sorted by year.
+    pub fn read_all_years() -> Vec<(Year, Self)> {
+        let Ok(entries) = fs::read_dir("data") else {
+            return vec![];
+        };
+
+        let mut years: Vec<Year> = entries
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+            .filter(|year| get_timings_path(*year).exists())
+            .collect();
+
+        years.sort_unstable();
+
+        years
+            .into_iter()
+            .map(|year| (year, Timings::read_from_file(year)))
+            .collect()
+    }
+
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Heap usage and resources that the new run did not measure are kept from `self`.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+
+            if let Some(stored) = self.data.iter().find(|t| t.day == timing.day) {
+                timing.parse_memory = timing.parse_memory.or(stored.parse_memory.clone());
+                timing.part_1_memory = timing.part_1_memory.or(stored.part_1_memory.clone());
+                timing.part_2_memory = timing.part_2_memory.or(stored.part_2_memory.clone());
+                timing.resources = timing.resources.or(stored.resources.clone());
+            }
+
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,7 +133,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -87,7 +169,14 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
+
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
 
+    fn try_from(json: &JsonValue) -> Result<Self, Self::Error> {
         let json_data = json
             .get::<HashMap<String, JsonValue>>()
             .ok_or("expected JSON document to be an object.")?
@@ -114,6 +203,14 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
 
@@ -133,6 +230,49 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        for (key, memory) in [
+            ("parse_memory", &value.parse_memory),
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+        ] {
+            map.insert(
+                key.into(),
+                memory.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        map.insert(
+            "resources".into(),
+            value
+                .resources
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -161,15 +301,53 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // timings stored before parse stages were reported do not have this key.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // timings stored before stats were collected do not have these keys.
+        let stats = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => BenchStats::try_from(v).map(Some),
+        };
+
+        // memory is only measured with `cargo time --memory`.
+        let memory = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => MemoryStats::try_from(v).map(Some),
+        };
+
+        let resources = match json.get("resources") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(ResourceUsage::try_from(v)?),
+        };
+
         Ok(Timing {
             day,
+            parse: parse.cloned(),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            parse_stats: stats("parse_stats")?,
+            parse_memory: memory("parse_memory")?,
+            part_1_memory: memory("part_1_memory")?,
+            part_2_memory: memory("part_2_memory")?,
+            resources,
             total_nanos,
         })
     }
@@ -177,6 +355,148 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("stddev_nanos".into(), nanos(value.stddev));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            min: duration("min_nanos")?,
+            median: duration("median_nanos")?,
+            mean: duration("mean_nanos")?,
+            stddev: duration("stddev_nanos")?,
+            p95: duration("p95_nanos")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as usize,
+        })
+    }
+}
+
+impl From<&MemoryStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &MemoryStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected memory stats.{key} to be a number."))
+        };
+
+        Ok(MemoryStats {
+            allocations: number("allocations")?,
+            total_bytes: number("total_bytes")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+impl From<&ResourceUsage> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &ResourceUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        map.insert(
+            "peak_rss_bytes".into(),
+            JsonValue::Number(value.peak_rss_bytes as f64),
+        );
+        map.insert("user_nanos".into(), nanos(value.user_time));
+        map.insert("system_nanos".into(), nanos(value.system_time));
+        map.insert("wall_nanos".into(), nanos(value.wall_time));
+        map.insert("cpu_ratio".into(), JsonValue::Number(value.cpu_ratio()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for ResourceUsage {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected resource usage to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected resources.{key} to be a number."))
+        };
+
+        // the CPU/wall ratio is derived, it is only stored for readers of the file.
+        Ok(ResourceUsage {
+            peak_rss_bytes: number("peak_rss_bytes")?,
+            user_time: Duration::from_nanos(number("user_nanos")?),
+            system_time: Duration::from_nanos(number("system_nanos")?),
+            wall_time: Duration::from_nanos(number("wall_nanos")?),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
@@ -188,20 +508,44 @@ mod tests {
             data: vec![
                 Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 4e+10,
                 },
             ],
@@ -209,6 +553,9 @@ mod tests {
     }
 
     mod deserialization {
+        use std::time::Duration;
+        use tinyjson::JsonValue;
+
         use crate::{day, template::timings::Timings};
 
         #[test]
@@ -223,6 +570,47 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "2ms", "part_2": null, "total_nanos": 2000000, "part_1_stats": { "min_nanos": 1000000, "median_nanos": 2000000, "mean_nanos": 2500000, "stddev_nanos": 500000, "p95_nanos": 4000000, "samples": 100, "outliers": 2 }, "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median, Duration::from_millis(2));
+            assert_eq!(stats.p95, Duration::from_millis(4));
+            assert_eq!(stats.samples, 100);
+            assert_eq!(stats.outliers, 2);
+            assert!(timing.part_2_stats.is_none());
+        }
+
+        #[test]
+        fn handles_json_parse_stages() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "1ms", "part_1": "2ms", "part_2": null, "total_nanos": 3000000, "parse_stats": { "min_nanos": 1000000, "median_nanos": 1000000, "mean_nanos": 1000000, "stddev_nanos": 0, "p95_nanos": 1000000, "samples": 10, "outliers": 0 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse, Some("1ms".into()));
+            assert_eq!(timing.median(0), Some(Duration::from_millis(1)));
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let roundtrip = Timings::try_from(json).unwrap();
+            assert_eq!(roundtrip.data[0].parse, Some("1ms".into()));
+            assert_eq!(roundtrip.data[0].parse_stats, timing.parse_stats);
+        }
+
+        #[test]
+        fn handles_json_resources() {
+            let json = r#"{ "data": [{ "day": "06", "part_1": "2ms", "part_2": "8ms", "total_nanos": 10000000, "resources": { "peak_rss_bytes": 4194304, "user_nanos": 30000000, "system_nanos": 10000000, "wall_nanos": 20000000, "cpu_ratio": 2 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let resources = timings.data[0].resources.as_ref().unwrap();
+            assert_eq!(resources.peak_rss_bytes, 4194304);
+            assert_eq!(resources.user_time, Duration::from_millis(30));
+            assert_eq!(resources.cpu_ratio(), 2.0);
+
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let roundtrip = Timings::try_from(json).unwrap();
+            assert_eq!(roundtrip.data[0].resources, timings.data[0].resources);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -279,13 +667,21 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +689,21 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,19 +711,28 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
     mod merge {
         use crate::{
             day,
+            template::runner::MemoryStats,
             template::timings::{Timing, Timings},
         };
 
@@ -331,8 +744,16 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -351,8 +772,16 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    parse_stats: None,
+                    parse_memory: None,
+                    part_1_memory: None,
+                    part_2_memory: None,
+                    resources: None,
                     total_nanos: 0_f64,
                 }],
             };
@@ -365,6 +794,29 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_unmeasured_memory() {
+            let memory = |peak_bytes| MemoryStats {
+                allocations: 1,
+                total_bytes: peak_bytes,
+                peak_bytes,
+            };
+
+            let mut timings = get_mock_timings();
+            timings.data[1].part_1_memory = Some(memory(10));
+            timings.data[1].part_2_memory = Some(memory(20));
+
+            let mut other = get_mock_timings();
+            other.data[1].total_nanos = 0_f64;
+            other.data[1].part_2_memory = Some(memory(30));
+
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].part_1_memory, Some(memory(10)));
+            assert_eq!(merged.data[1].part_2_memory, Some(memory(30)));
+            assert_eq!(merged.data[0].part_1_memory, None);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{Day, DayFromStrError};
+
+/// The year of the first advent of code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// The number of puzzles per event, starting from the given year.
+/// Events up to 2024 ran for 25 days, newer events have 12 puzzles.
+const EVENT_DAYS: [(u16, u8); 2] = [(FIRST_YEAR, 25), (2025, 12)];
+
+/// A valid year of advent (i.e. an integer of 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(FIRST_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Reads the year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of puzzles of this year's event.
+    pub const fn days(self) -> u8 {
+        let mut days = EVENT_DAYS[0].1;
+        let mut i = 0;
+        while i < EVENT_DAYS.len() {
+            if self.0 >= EVENT_DAYS[i].0 {
+                days = EVENT_DAYS[i].1;
+            }
+            i += 1;
+        }
+        days
+    }
+
+    /// The most puzzles any event has. No year has a puzzle for a later day.
+    pub const MAX_DAYS: u8 = {
+        let mut days = 0;
+        let mut i = 0;
+        while i < EVENT_DAYS.len() {
+            if EVENT_DAYS[i].1 > days {
+                days = EVENT_DAYS[i].1;
+            }
+            i += 1;
+        }
+        days
+    };
+
+    /// Checks whether this year's event has a puzzle for a [`Day`].
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.days()
+    }
+
+    /// Parses a [`Day`] that this year's event has a puzzle for.
+    pub fn parse_day(self, s: &str) -> Result<Day, DayFromStrError> {
+        let error = DayFromStrError { year: Some(self) };
+        let day = s.parse().map_err(|_| error)?;
+        Day::new_in(day, self).ok_or(error)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a value of 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), Year(2024));
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn configures_days_per_event() {
+        assert_eq!(Year(2015).days(), 25);
+        assert_eq!(Year(2024).days(), 25);
+        assert_eq!(Year(2025).days(), 12);
+        assert!(Year(2024).has_day(crate::day!(25)));
+        assert!(!Year(2025).has_day(crate::day!(13)));
+    }
+
+    #[test]
+    fn creates_years_in_const_context() {
+        assert_eq!(crate::year!(2023), Year(2023));
+    }
+}